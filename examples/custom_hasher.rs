@@ -1,6 +1,10 @@
 use std::sync::Arc;
 
-use libmhash::{paranoid_hash::Hasher, prelude::*, Result};
+use libmhash::{
+    paranoid_hash::{Hasher, HasherRegistry},
+    prelude::*,
+    Result,
+};
 
 // a very simple hasher
 struct MyHasher {
@@ -38,6 +42,10 @@ impl Hasher for MyHasher {
 }
 
 fn main() {
+    // register the hasher, so it can be created by name, e.g. from a config file
+    let mut registry = HasherRegistry::default();
+    registry.register("my-hasher", || Box::new(MyHasher { state: 0 }));
+
     // create a hasher server
     let mut server = Builder::new()
         .on_result(Some(|r: &HasherResult<Arc<&str>>| println!("{:#?}", r)))
@@ -53,13 +61,17 @@ fn main() {
             let dir = entry.unwrap();
             if dir.path().is_file() {
                 // create hashers
-                let mut hashers = vec![];
-
-                // tags can be String or something else, but better choose something cheap to clone.
-                hashers.push(HasherWrapper::new(
-                    Arc::new("My Hasher"),
-                    MyHasher { state: 0 },
-                ));
+                let hashers = vec![
+                    // tags can be String or something else, but better choose something cheap to clone.
+                    HasherWrapper::new(Arc::new("My Hasher"), MyHasher { state: 0 }),
+                    // or from the registry, names are case insensitive
+                    HasherWrapper::create_from_registry(
+                        &registry,
+                        Arc::new("Registry"),
+                        "My_Hasher",
+                    )
+                    .unwrap(),
+                ];
 
                 // send files
                 sender.push_file(dir.path(), hashers);
@@ -13,16 +13,12 @@ fn main() {
     // you can also send files without spawning a new thread
     std::thread::spawn(move || {
         // create hashers
-        let mut hashers = vec![];
-
-        // tags can be String or something else, but better choose something cheap to clone.
-        hashers.push(HasherWrapper::new(
-            HasherTag::SHA1,
-            libmhash::paranoid_hash::SHA1::new(),
-        ));
-
-        // you can also create HasherWrapper from HasherTags
-        hashers.push(HasherWrapper::create_from_tag(HasherTag::MD5));
+        let hashers = vec![
+            // tags can be String or something else, but better choose something cheap to clone.
+            HasherWrapper::new(HasherTag::SHA1, libmhash::paranoid_hash::SHA1::new()),
+            // you can also create HasherWrapper from HasherTags
+            HasherWrapper::create_from_tag(HasherTag::MD5),
+        ];
 
         let mut fragment_sender = sender.fragment_sender("Temp Data", hashers);
 
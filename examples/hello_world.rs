@@ -16,16 +16,12 @@ fn main() {
             let dir = entry.unwrap();
             if dir.path().is_file() {
                 // create hashers
-                let mut hashers = vec![];
-
-                // tags can be String or something else, but better choose something cheap to clone.
-                hashers.push(HasherWrapper::new(
-                    HasherTag::SHA1,
-                    libmhash::paranoid_hash::SHA1::new(),
-                ));
-
-                // you can also create HasherWrapper from HasherTags
-                hashers.push(HasherWrapper::create_from_tag(HasherTag::MD5));
+                let hashers = vec![
+                    // tags can be String or something else, but better choose something cheap to clone.
+                    HasherWrapper::new(HasherTag::SHA1, libmhash::paranoid_hash::SHA1::new()),
+                    // you can also create HasherWrapper from HasherTags
+                    HasherWrapper::create_from_tag(HasherTag::MD5),
+                ];
 
                 // send files
                 sender.push_file(dir.path(), hashers);
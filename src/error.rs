@@ -1,5 +1,9 @@
+use alloc::string::String;
+
 use thiserror::Error;
 
+use crate::paranoid_hash::{Backend, HasherTag};
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("Data length overflowed, maximum allow data length is {0}")]
@@ -8,16 +12,66 @@ pub enum Error {
     DataLengthMismatched(usize, usize),
     #[error("Data length was larger than block size, data length: {0}, block size: {1}")]
     DataTooLarge(usize, usize),
+    #[error("Data length did not match bit length, bit length: {0}, data length: {1}")]
+    BitLengthMismatched(usize, usize),
+    #[error("Message length which is not a multiple of 8 bits is not supported")]
+    PartialBitsUnsupported,
     #[error("Trying to get digest before calling udpate_last")]
     NotFinished,
     #[error("Calling update after hasher finished")]
     UpdatingAfterFinished,
     #[error("Block size given was not a multiple of base block size. Which is 128.")]
     IncorrectBlockSize,
+    #[error("Rate must be between 1 and {1} bytes, got {0}")]
+    IncorrectRate(usize, usize),
+    #[error("Saving and restoring state is not supported by this hasher")]
+    StateUnsupported,
+    #[error("Invalid hasher state: {0}")]
+    InvalidState(&'static str),
+    #[error("Cloning is not supported by this hasher")]
+    CloneUnsupported,
+    #[error("Invalid digest: {0}")]
+    InvalidDigest(&'static str),
+    #[error("Unknown hash algorithm: {0}")]
+    UnknownAlgorithm(String),
+    #[error("{0} has no implementation in the {1:?} backend")]
+    BackendUnavailable(HasherTag, Backend),
+    #[error("Implementations disagreed on the digest, {first} vs {second}")]
+    ImplementationMismatch { first: String, second: String },
+    #[error(
+        "Hashers can't be paired, block sizes: {block_sizes:?}, digest sizes: {digest_sizes:?}"
+    )]
+    HasherSizeMismatch {
+        block_sizes: (usize, usize),
+        digest_sizes: (usize, usize),
+    },
+    #[error("Invalid test vectors at line {0}: {1}")]
+    InvalidTestVectors(usize, &'static str),
+    #[error("Self-test failed for {0} in the {1:?} backend")]
+    SelfTestFailed(HasherTag, Backend),
+    #[error("Algorithm is not allowed by the policy: {0}")]
+    AlgorithmNotApproved(String),
+    #[error("Algorithm is weak against collision attacks: {0}")]
+    WeakAlgorithm(String),
+    #[error("Digest mismatch, expected {expected}, got {actual}")]
+    DigestMismatch { expected: String, actual: String },
+    #[cfg(feature = "std")]
     #[error("Io Error.")]
     IoError(#[from] std::io::Error),
     #[error("Data ended already! No more new id is allowed!")]
     DataEnded,
+    #[error("SHA-1 collision attack detected")]
+    CollisionDetected,
+}
+
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::IoError(error) => error,
+            error => std::io::Error::other(error),
+        }
+    }
 }
 
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
@@ -1,7 +1,10 @@
 use std::{fmt::Debug, hash::Hash, marker::PhantomData, sync::Arc};
 
 use crate::{
-    hasher_server::{HasherError, HasherProgress, HasherResult, HasherServer},
+    hasher_server::{
+        AlgorithmPolicy, HasherError, HasherProgress, HasherResult, HasherServer,
+        WeakAlgorithmPolicy,
+    },
     simple_semaphore::SimpleSemaphore,
 };
 
@@ -13,6 +16,9 @@ pub struct Builder<Tag, P = (), R = (), E = ()> {
     progress_callback: Option<P>,
     result_callback: Option<R>,
     error_callback: Option<E>,
+    algorithm_policy: AlgorithmPolicy,
+    weak_algorithm_policy: WeakAlgorithmPolicy,
+    self_test: bool,
 
     marker: PhantomData<Tag>,
 }
@@ -21,6 +27,7 @@ impl<Tag> Builder<Tag>
 where
     Tag: Clone + Eq + Hash + Send + 'static,
 {
+    #[allow(clippy::type_complexity)]
     pub fn new() -> Builder<
         Tag,
         impl FnMut(&HasherProgress<Tag>),
@@ -34,6 +41,9 @@ where
             progress_callback: Some(empty_progress_callback),
             result_callback: Some(empty_result_callback),
             error_callback: Some(empty_error_callback),
+            algorithm_policy: AlgorithmPolicy::AllowAll,
+            weak_algorithm_policy: WeakAlgorithmPolicy::Allow,
+            self_test: false,
             marker: PhantomData,
         };
 
@@ -66,6 +76,13 @@ pub trait BuilderTrait {
 
     fn approximate_block_size(self, block_size: usize) -> Self;
 
+    fn algorithm_policy(self, algorithm_policy: AlgorithmPolicy) -> Self;
+
+    fn weak_algorithm_policy(self, weak_algorithm_policy: WeakAlgorithmPolicy) -> Self;
+
+    /// Runs `paranoid_hash::self_test` in `build`, which fails if any algorithm is broken.
+    fn self_test(self, self_test: bool) -> Self;
+
     fn on_progress<F: FnMut(&HasherProgress<Self::Tag>)>(
         self,
         progress_callback: Option<F>,
@@ -130,6 +147,24 @@ where
         Self { block_size, ..self }
     }
 
+    fn algorithm_policy(self, algorithm_policy: AlgorithmPolicy) -> Self {
+        Self {
+            algorithm_policy,
+            ..self
+        }
+    }
+
+    fn weak_algorithm_policy(self, weak_algorithm_policy: WeakAlgorithmPolicy) -> Self {
+        Self {
+            weak_algorithm_policy,
+            ..self
+        }
+    }
+
+    fn self_test(self, self_test: bool) -> Self {
+        Self { self_test, ..self }
+    }
+
     fn on_progress<F: FnMut(&HasherProgress<Tag>)>(
         self,
         progress_callback: Option<F>,
@@ -141,6 +176,9 @@ where
             progress_callback,
             result_callback: self.result_callback,
             error_callback: self.error_callback,
+            algorithm_policy: self.algorithm_policy,
+            weak_algorithm_policy: self.weak_algorithm_policy,
+            self_test: self.self_test,
             marker: PhantomData,
         }
     }
@@ -156,6 +194,9 @@ where
             progress_callback: self.progress_callback,
             result_callback,
             error_callback: self.error_callback,
+            algorithm_policy: self.algorithm_policy,
+            weak_algorithm_policy: self.weak_algorithm_policy,
+            self_test: self.self_test,
             marker: PhantomData,
         }
     }
@@ -171,6 +212,9 @@ where
             progress_callback: self.progress_callback,
             result_callback: self.result_callback,
             error_callback,
+            algorithm_policy: self.algorithm_policy,
+            weak_algorithm_policy: self.weak_algorithm_policy,
+            self_test: self.self_test,
             marker: PhantomData,
         }
     }
@@ -180,9 +224,13 @@ where
             0 => Self::BASE_BLOCK_SIZE,
             others => others,
         };
-        if block_size % Self::BASE_BLOCK_SIZE != 0 {
+        if !block_size.is_multiple_of(Self::BASE_BLOCK_SIZE) {
             return Err(crate::Error::IncorrectBlockSize);
         }
+
+        if self.self_test {
+            crate::paranoid_hash::self_test()?;
+        }
         let minimum_block_size = Self::BASE_BLOCK_SIZE;
 
         //floor block size
@@ -218,6 +266,8 @@ where
             progress_callback,
             result_callback,
             error_callback,
+            algorithm_policy: self.algorithm_policy,
+            weak_algorithm_policy: self.weak_algorithm_policy,
         })
     }
 }
@@ -249,6 +299,9 @@ impl<Tag, P, R, E> Debug for Builder<Tag, P, R, E> {
                     None => &"None",
                 },
             )
+            .field("algorithm_policy", &self.algorithm_policy)
+            .field("weak_algorithm_policy", &self.weak_algorithm_policy)
+            .field("self_test", &self.self_test)
             .field("marker", &self.marker)
             .finish()
     }
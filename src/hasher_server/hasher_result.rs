@@ -2,7 +2,8 @@ use std::{fmt::Debug, hash::Hash};
 
 use crate::{
     hasher_server::{hasher_wrapper::HasherWrapper, Identifier},
-    paranoid_hash::Hasher,
+    paranoid_hash::{Digest, Hasher, HasherTag},
+    Result,
 };
 
 pub struct HasherResult<'a, Tag>
@@ -11,12 +12,18 @@ where
 {
     pub identifier: Identifier,
     pub tag: Tag,
+    /// Known for hashers created from a `HasherTag` or given with `HasherWrapper::with_algorithm`,
+    /// use it with `Verification` to tell how much a match can be trusted.
+    pub algorithm: Option<HasherTag>,
     pub hasher: &'a dyn Hasher,
 }
 
-impl<'a> std::fmt::Debug for dyn Hasher + 'a {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:02x?}", self.digest())
+impl<'a, Tag> HasherResult<'a, Tag>
+where
+    Tag: Clone + Eq + Hash + Send,
+{
+    pub fn digest(&self) -> Result<Digest> {
+        Digest::new(self.hasher.digest()?)
     }
 }
 
@@ -28,6 +35,7 @@ where
         f.debug_struct("HasherResult")
             .field("identifier", &self.identifier)
             .field("tag", &self.tag)
+            .field("algorithm", &self.algorithm)
             .field("hasher", &self.hasher)
             .finish()
     }
@@ -2,10 +2,8 @@ use std::{hash::Hash, sync::Arc};
 
 use crate::{
     hasher_server::sync_unsafe_cell::SyncUnsafeCell,
-    paranoid_hash::{
-        Hasher, HasherTag, CRC32, CRC32C, MD2, MD4, MD5, SHA1, SHA2_224, SHA2_256, SHA2_384,
-        SHA2_512, SHA3_224, SHA3_256, SHA3_384, SHA3_512,
-    },
+    paranoid_hash::{Backend, Hasher, HasherRegistry, HasherTag},
+    Result,
 };
 
 pub struct HasherWrapper<Tag = HasherTag>
@@ -15,26 +13,24 @@ where
     pub(crate) tag: Tag,
     //Same hasher can't be accessed by multiple threads at the same time is guaranteed by the TagThreadPool
     pub(crate) hasher: Arc<SyncUnsafeCell<dyn Hasher>>,
+    pub(crate) algorithm: Option<HasherTag>,
 }
 
 impl HasherWrapper {
     pub fn create_from_tag(tag: HasherTag) -> HasherWrapper<HasherTag> {
-        match tag {
-            HasherTag::CRC32 => HasherWrapper::<HasherTag>::new(tag, CRC32::new()),
-            HasherTag::CRC32C => HasherWrapper::<HasherTag>::new(tag, CRC32C::new()),
-            HasherTag::MD2 => HasherWrapper::<HasherTag>::new(tag, MD2::new()),
-            HasherTag::MD4 => HasherWrapper::<HasherTag>::new(tag, MD4::new()),
-            HasherTag::MD5 => HasherWrapper::<HasherTag>::new(tag, MD5::new()),
-            HasherTag::SHA1 => HasherWrapper::<HasherTag>::new(tag, SHA1::new()),
-            HasherTag::SHA2_224 => HasherWrapper::<HasherTag>::new(tag, SHA2_224::new()),
-            HasherTag::SHA2_256 => HasherWrapper::<HasherTag>::new(tag, SHA2_256::new()),
-            HasherTag::SHA2_384 => HasherWrapper::<HasherTag>::new(tag, SHA2_384::new()),
-            HasherTag::SHA2_512 => HasherWrapper::<HasherTag>::new(tag, SHA2_512::new()),
-            HasherTag::SHA3_224 => HasherWrapper::<HasherTag>::new(tag, SHA3_224::new()),
-            HasherTag::SHA3_256 => HasherWrapper::<HasherTag>::new(tag, SHA3_256::new()),
-            HasherTag::SHA3_384 => HasherWrapper::<HasherTag>::new(tag, SHA3_384::new()),
-            HasherTag::SHA3_512 => HasherWrapper::<HasherTag>::new(tag, SHA3_512::new()),
-        }
+        HasherWrapper::<HasherTag>::new(tag, tag.new_hasher()).with_algorithm(tag)
+    }
+
+    /// Creates the hasher from the given backend, see `HasherTag::new_hasher_with`.
+    ///
+    /// With `Backend::CrossCheck` a disagreement between the implementations is reported as an
+    /// `Error::ImplementationMismatch` through the error callback.
+    pub fn create_from_tag_with(
+        tag: HasherTag,
+        backend: Backend,
+    ) -> Result<HasherWrapper<HasherTag>> {
+        tag.new_hasher_with(backend)
+            .map(|hasher| HasherWrapper::<HasherTag>::new(tag, hasher).with_algorithm(tag))
     }
 }
 
@@ -46,13 +42,48 @@ where
         HasherWrapper {
             tag,
             hasher: Arc::new(SyncUnsafeCell::new(hasher)),
+            algorithm: None,
+        }
+    }
+
+    /// Declares the algorithm of the hasher, which is checked by the `AlgorithmPolicy` of the
+    /// server. Hashers created from a `HasherTag` have it already.
+    pub fn with_algorithm(self, algorithm: HasherTag) -> Self {
+        Self {
+            algorithm: Some(algorithm),
+            ..self
         }
     }
 
+    pub fn algorithm(&self) -> Option<HasherTag> {
+        self.algorithm
+    }
+
+    /// Wraps a hasher from the digest crate, so it can be used by the hasher server.
+    #[cfg(feature = "digest")]
+    pub fn from_digest<D: digest::Digest + 'static>(tag: Tag, digest: D) -> HasherWrapper<Tag> {
+        Self::new(tag, crate::paranoid_hash::DigestHasher::new(digest))
+    }
+
+    /// Creates the hasher registered under the name, with its algorithm if the registry knows it,
+    /// see `HasherRegistry::algorithm`.
+    pub fn create_from_registry(
+        registry: &HasherRegistry,
+        tag: Tag,
+        name: &str,
+    ) -> Result<HasherWrapper<Tag>> {
+        let wrapper = Self::new(tag, registry.create(name)?);
+        Ok(match registry.algorithm(name) {
+            Some(algorithm) => wrapper.with_algorithm(algorithm),
+            None => wrapper,
+        })
+    }
+
     pub fn shallow_clone(&self) -> Self {
         Self {
             tag: self.tag.clone(),
             hasher: Arc::clone(&self.hasher),
+            algorithm: self.algorithm,
         }
     }
 }
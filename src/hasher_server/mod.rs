@@ -11,6 +11,7 @@ mod hasher_result;
 mod hasher_wrapper;
 mod identifier;
 mod operation;
+mod policy;
 mod server;
 mod sync_unsafe_cell;
 
@@ -25,5 +26,7 @@ pub use hasher_progress::HasherProgress;
 pub use hasher_result::HasherResult;
 pub use hasher_wrapper::HasherWrapper;
 pub use identifier::Identifier;
+pub use policy::AlgorithmPolicy;
+pub use policy::WeakAlgorithmPolicy;
 pub use server::HasherServer;
 pub use server::HasherServerTrait;
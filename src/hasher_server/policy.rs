@@ -0,0 +1,312 @@
+use crate::{paranoid_hash::HasherTag, Error, Result};
+
+/// Which algorithms a server accepts, hashers of the other ones are rejected with
+/// `Error::AlgorithmNotApproved` through the error callback.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum AlgorithmPolicy {
+    #[default]
+    AllowAll,
+    /// Only SHA-2 and SHA-3, which are approved by FIPS 180-4 and FIPS 202.
+    ///
+    /// The algorithm of a hasher is only known if it's created from a `HasherTag`, from a tag in a
+    /// `HasherRegistry`, or given with `HasherWrapper::with_algorithm`, other hashers are rejected.
+    ApprovedOnly,
+}
+
+impl AlgorithmPolicy {
+    pub fn check(self, algorithm: Option<HasherTag>) -> Result<()> {
+        match (self, algorithm) {
+            (AlgorithmPolicy::AllowAll, _) => Ok(()),
+            (AlgorithmPolicy::ApprovedOnly, Some(tag)) if is_approved(tag) => Ok(()),
+            (AlgorithmPolicy::ApprovedOnly, Some(tag)) => {
+                Err(Error::AlgorithmNotApproved(tag.name().to_string()))
+            }
+            (AlgorithmPolicy::ApprovedOnly, None) => {
+                Err(Error::AlgorithmNotApproved("unknown algorithm".to_string()))
+            }
+        }
+    }
+}
+
+/// What a server does with hashers of weak algorithms, see `SecurityStrength::is_weak`.
+///
+/// Hashers whose algorithms are unknown can't be shown to be strong, so they are treated as weak
+/// ones, the same way `AlgorithmPolicy::ApprovedOnly` rejects them.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum WeakAlgorithmPolicy {
+    #[default]
+    Allow,
+    /// Reports `Error::WeakAlgorithm` through the error callback, the hasher still runs.
+    Warn,
+    /// Rejects the hasher with `Error::WeakAlgorithm` through the error callback.
+    Deny,
+}
+
+impl WeakAlgorithmPolicy {
+    pub fn check(self, algorithm: Option<HasherTag>) -> Result<()> {
+        match (self, algorithm) {
+            (WeakAlgorithmPolicy::Allow, _) => Ok(()),
+            (_, Some(tag)) if !tag.is_weak() => Ok(()),
+            (_, Some(tag)) => Err(Error::WeakAlgorithm(tag.name().to_string())),
+            (_, None) => Err(Error::WeakAlgorithm("unknown algorithm".to_string())),
+        }
+    }
+}
+
+fn is_approved(tag: HasherTag) -> bool {
+    match tag {
+        #[cfg(feature = "sha2")]
+        HasherTag::SHA2_224 | HasherTag::SHA2_256 | HasherTag::SHA2_384 | HasherTag::SHA2_512 => {
+            true
+        }
+        #[cfg(feature = "sha3")]
+        HasherTag::SHA3_224 | HasherTag::SHA3_256 | HasherTag::SHA3_384 | HasherTag::SHA3_512 => {
+            true
+        }
+        #[allow(unreachable_patterns)]
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    use std::thread;
+
+    #[cfg(feature = "md5")]
+    use crate::paranoid_hash::MD5;
+    use crate::{
+        hasher_server::{
+            Builder, BuilderTrait, HasherError, HasherResult, HasherServerTrait, HasherWrapper,
+        },
+        paranoid_hash::{HasherRegistry, HasherTag},
+        Error,
+    };
+
+    use super::{AlgorithmPolicy, WeakAlgorithmPolicy};
+
+    #[test]
+    fn check_tests() {
+        let policy = AlgorithmPolicy::ApprovedOnly;
+        #[cfg(feature = "sha2")]
+        assert!(policy.check(Some(HasherTag::SHA2_256)).is_ok());
+        #[cfg(feature = "sha3")]
+        assert!(policy.check(Some(HasherTag::SHA3_512)).is_ok());
+        let not_approved = [
+            #[cfg(feature = "md2")]
+            HasherTag::MD2,
+            #[cfg(feature = "md4")]
+            HasherTag::MD4,
+            #[cfg(feature = "md5")]
+            HasherTag::MD5,
+            #[cfg(feature = "sha1")]
+            HasherTag::SHA1,
+            #[cfg(feature = "sha1dc")]
+            HasherTag::SHA1DC,
+            #[cfg(feature = "crc32")]
+            HasherTag::CRC32,
+        ];
+        for tag in not_approved {
+            assert!(matches!(
+                policy.check(Some(tag)),
+                Err(Error::AlgorithmNotApproved(_))
+            ));
+        }
+        assert!(policy.check(None).is_err());
+
+        for tag in HasherTag::all() {
+            assert!(AlgorithmPolicy::AllowAll.check(Some(*tag)).is_ok());
+        }
+    }
+
+    #[test]
+    fn weak_check_tests() {
+        for policy in [WeakAlgorithmPolicy::Warn, WeakAlgorithmPolicy::Deny] {
+            #[cfg(feature = "md5")]
+            assert!(matches!(
+                policy.check(Some(HasherTag::MD5)),
+                Err(Error::WeakAlgorithm(_))
+            ));
+            #[cfg(feature = "crc32")]
+            assert!(policy.check(Some(HasherTag::CRC32)).is_err());
+            #[cfg(feature = "haval")]
+            assert!(policy.check(Some(HasherTag::HAVAL_256_4)).is_err());
+            #[cfg(feature = "snefru")]
+            assert!(policy.check(Some(HasherTag::SNEFRU_256)).is_err());
+            #[cfg(feature = "sha2")]
+            assert!(policy.check(Some(HasherTag::SHA2_256)).is_ok());
+            assert!(matches!(
+                policy.check(None),
+                Err(Error::WeakAlgorithm(name)) if name == "unknown algorithm"
+            ));
+        }
+        assert!(WeakAlgorithmPolicy::Allow.check(None).is_ok());
+        #[cfg(feature = "md5")]
+        assert!(WeakAlgorithmPolicy::Allow
+            .check(Some(HasherTag::MD5))
+            .is_ok());
+    }
+
+    fn run_server(
+        algorithm_policy: AlgorithmPolicy,
+        weak_algorithm_policy: WeakAlgorithmPolicy,
+        hashers: fn() -> Vec<HasherWrapper<&'static str>>,
+    ) -> (Vec<&'static str>, Vec<(&'static str, Error)>) {
+        let mut results = Vec::new();
+        let mut errors = Vec::new();
+        let mut server = Builder::new()
+            .algorithm_policy(algorithm_policy)
+            .weak_algorithm_policy(weak_algorithm_policy)
+            .on_result(Some(|r: &HasherResult<&'static str>| results.push(r.tag)))
+            .on_error(Some(|e: &HasherError<&'static str>| {
+                let error = match &e.error {
+                    Error::AlgorithmNotApproved(name) => Error::AlgorithmNotApproved(name.clone()),
+                    Error::WeakAlgorithm(name) => Error::WeakAlgorithm(name.clone()),
+                    error => panic!("{error}"),
+                };
+                errors.push((e.tag.unwrap(), error))
+            }))
+            .build()
+            .unwrap();
+
+        let mut sender = server.data_sender();
+        thread::spawn(move || {
+            sender
+                .fragment_sender("data", hashers())
+                .push_last_data(&[]);
+            sender.end();
+        });
+        server.compute();
+
+        results.sort();
+        errors.sort_by_key(|(tag, _)| *tag);
+        (results, errors)
+    }
+
+    #[cfg(all(feature = "md5", feature = "sha2"))]
+    #[test]
+    fn weak_server_test() {
+        let hashers = || {
+            vec![
+                HasherWrapper::new("md5", MD5::new()).with_algorithm(HasherTag::MD5),
+                HasherWrapper::new("sha256", HasherTag::SHA2_256.new_hasher())
+                    .with_algorithm(HasherTag::SHA2_256),
+                HasherWrapper::new("unknown", MD5::new()),
+            ]
+        };
+
+        let (results, errors) = run_server(
+            AlgorithmPolicy::AllowAll,
+            WeakAlgorithmPolicy::Allow,
+            hashers,
+        );
+        assert_eq!(results, ["md5", "sha256", "unknown"]);
+        assert!(errors.is_empty());
+
+        let (results, errors) = run_server(
+            AlgorithmPolicy::AllowAll,
+            WeakAlgorithmPolicy::Warn,
+            hashers,
+        );
+        assert_eq!(results, ["md5", "sha256", "unknown"]);
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(&errors[0], ("md5", Error::WeakAlgorithm(name)) if name == "md5"));
+        assert!(matches!(&errors[1], ("unknown", Error::WeakAlgorithm(_))));
+
+        // unknown algorithms can't be shown to be strong
+        let (results, errors) = run_server(
+            AlgorithmPolicy::AllowAll,
+            WeakAlgorithmPolicy::Deny,
+            hashers,
+        );
+        assert_eq!(results, ["sha256"]);
+        assert!(matches!(
+            &errors[..],
+            [
+                ("md5", Error::WeakAlgorithm(_)),
+                ("unknown", Error::WeakAlgorithm(_)),
+            ]
+        ));
+    }
+
+    #[cfg(all(feature = "md5", feature = "sha2"))]
+    #[test]
+    fn registry_server_test() {
+        let hashers = || {
+            let mut registry = HasherRegistry::default();
+            registry.register("my-md5", || Box::new(MD5::new()));
+            ["sha256", "md5", "my-md5"]
+                .into_iter()
+                .map(|name| HasherWrapper::create_from_registry(&registry, name, name).unwrap())
+                .collect()
+        };
+
+        let (results, errors) = run_server(
+            AlgorithmPolicy::ApprovedOnly,
+            WeakAlgorithmPolicy::Allow,
+            hashers,
+        );
+        assert_eq!(results, ["sha256"]);
+        assert!(matches!(
+            &errors[..],
+            [
+                ("md5", Error::AlgorithmNotApproved(name)),
+                ("my-md5", Error::AlgorithmNotApproved(_)),
+            ] if name == "md5"
+        ));
+
+        let (results, errors) = run_server(
+            AlgorithmPolicy::AllowAll,
+            WeakAlgorithmPolicy::Deny,
+            hashers,
+        );
+        assert_eq!(results, ["sha256"]);
+        assert!(matches!(
+            &errors[..],
+            [
+                ("md5", Error::WeakAlgorithm(_)),
+                ("my-md5", Error::WeakAlgorithm(_)),
+            ]
+        ));
+    }
+
+    #[cfg(all(feature = "md5", feature = "sha2"))]
+    #[test]
+    fn server_test() {
+        let mut results = Vec::new();
+        let mut errors = Vec::new();
+        let mut server = Builder::new()
+            .algorithm_policy(AlgorithmPolicy::ApprovedOnly)
+            .on_result(Some(|r: &HasherResult<&'static str>| results.push(r.tag)))
+            .on_error(Some(|e: &HasherError<&'static str>| {
+                assert!(matches!(e.error, Error::AlgorithmNotApproved(_)));
+                errors.push(e.tag.unwrap())
+            }))
+            .build()
+            .unwrap();
+
+        let mut sender = server.data_sender();
+        thread::spawn(move || {
+            let hashers = vec![
+                HasherWrapper::new("sha256", HasherTag::SHA2_256.new_hasher())
+                    .with_algorithm(HasherTag::SHA2_256),
+                HasherWrapper::new("md5", HasherTag::MD5.new_hasher())
+                    .with_algorithm(HasherTag::MD5),
+                HasherWrapper::new("unknown", MD5::new()),
+            ];
+            sender.fragment_sender("data", hashers).push_last_data(&[]);
+
+            // rejecting every hasher doesn't stall the server
+            let hashers = vec![HasherWrapper::new("md5", MD5::new())];
+            sender.fragment_sender("more", hashers).push_last_data(&[]);
+
+            sender.end();
+        });
+        server.compute();
+
+        assert_eq!(results, ["sha256"]);
+        errors.sort();
+        assert_eq!(errors, ["md5", "md5", "unknown"]);
+    }
+}
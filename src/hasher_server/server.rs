@@ -22,6 +22,8 @@ where
     pub(super) progress_callback: Option<P>,
     pub(super) result_callback: Option<R>,
     pub(super) error_callback: Option<E>,
+    pub(super) algorithm_policy: AlgorithmPolicy,
+    pub(super) weak_algorithm_policy: WeakAlgorithmPolicy,
 }
 
 impl<Tag, P, R, E> HasherServer<Tag, P, R, E>
@@ -35,7 +37,7 @@ where
         hasher_threads: &TagThreadPool<IdentifierHasherTag<Tag>>,
     ) {
         let Some(hashers) = hasher_map.get(&data_wrapper.identifier) else {
-                return;
+            return;
         };
 
         for hasher_wrapper in hashers {
@@ -114,6 +116,48 @@ where
     }
 }
 
+impl<Tag, P, R, E> HasherServer<Tag, P, R, E>
+where
+    Tag: Clone + Eq + Hash + Send,
+    E: FnMut(&HasherError<Tag>),
+{
+    // rejected hashers are reported and dropped before any data is hashed
+    fn apply_policy(
+        &mut self,
+        identifier: &Identifier,
+        hashers: Vec<HasherWrapper<Tag>>,
+    ) -> Vec<HasherWrapper<Tag>> {
+        let algorithm_policy = self.algorithm_policy;
+        let weak_algorithm_policy = self.weak_algorithm_policy;
+        hashers
+            .into_iter()
+            .filter(|hasher| {
+                let result = algorithm_policy
+                    .check(hasher.algorithm)
+                    .and_then(|_| weak_algorithm_policy.check(hasher.algorithm));
+
+                match result {
+                    Ok(()) => true,
+                    Err(error) => {
+                        // warnings are reported but the hasher is kept
+                        let keep = matches!(error, Error::WeakAlgorithm(_))
+                            && weak_algorithm_policy == WeakAlgorithmPolicy::Warn;
+
+                        if let Some(callback) = self.error_callback.as_mut() {
+                            (callback)(&HasherError {
+                                identifier: identifier.clone(),
+                                tag: Some(hasher.tag.clone()),
+                                error,
+                            })
+                        }
+                        keep
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
 pub trait HasherServerTrait {
     type Tag: Clone + Eq + Hash + Send;
 
@@ -182,6 +226,11 @@ where
                         continue;
                     }
 
+                    let hashers = self.apply_policy(&identifier, hashers);
+                    if hashers.is_empty() {
+                        continue;
+                    }
+
                     hasher_map.insert(identifier, hashers);
                 }
                 Operation::EndOfNewIdentifier => end_of_list = true,
@@ -198,6 +247,7 @@ where
                     let pub_result = HasherResult {
                         identifier: result.identifier.clone(),
                         tag: result.hasher_wrapper.tag.clone(),
+                        algorithm: result.hasher_wrapper.algorithm,
                         hasher,
                     };
                     if let Some(callback) = self.result_callback.as_mut() {
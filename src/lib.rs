@@ -1,18 +1,24 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod hasher_server;
 pub mod paranoid_hash;
 
 pub use error::*;
 
 mod error;
+#[cfg(feature = "std")]
 mod simple_semaphore;
+#[cfg(feature = "std")]
 mod tag_thread_pool;
 
 pub mod prelude {
-    pub use crate::{
-        hasher_server::{
-            Builder, BuilderTrait, HasherError, HasherProgress, HasherResult, HasherServer,
-            HasherServerTrait, HasherWrapper,
-        },
-        paranoid_hash::HasherTag,
+    #[cfg(feature = "std")]
+    pub use crate::hasher_server::{
+        AlgorithmPolicy, Builder, BuilderTrait, HasherError, HasherProgress, HasherResult,
+        HasherServer, HasherServerTrait, HasherWrapper, WeakAlgorithmPolicy,
     };
+    pub use crate::paranoid_hash::HasherTag;
 }
@@ -0,0 +1,270 @@
+use alloc::boxed::Box;
+
+use crate::{
+    paranoid_hash::{CrossCheckHasher, Hasher, HasherTag},
+    Error, Result,
+};
+
+/// Which implementation to use for the algorithms that have more than one.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Backend {
+    /// Implementations of this crate, every algorithm has one.
+    Native,
+    /// Implementations backed by the RustCrypto crates and crc32fast/crc32c, they need the
+    /// `alter-impl` feature and only exist for some algorithms.
+    ///
+    /// The MD4 and MD5 ones can't save and restore state, the md4 and md-5 crates keep their
+    /// chaining values and compression functions private.
+    RustCrypto,
+    /// The RustCrypto one if there's one that can save and restore state, since they are usually
+    /// faster, otherwise the native one.
+    #[default]
+    Fastest,
+    /// Both the native and the RustCrypto ones on the same data, see `CrossCheckHasher`. Only for
+    /// the algorithms that have both.
+    CrossCheck,
+}
+
+/// Native implementations of the algorithms that also have RustCrypto ones.
+pub mod native {
+    #[cfg(feature = "crc32")]
+    pub use crate::paranoid_hash::crc32::mine::{CRC32, CRC32C};
+    #[cfg(feature = "md4")]
+    pub use crate::paranoid_hash::md4::mine::MD4;
+    #[cfg(feature = "md5")]
+    pub use crate::paranoid_hash::md5::mine::MD5;
+    #[cfg(feature = "sha1")]
+    pub use crate::paranoid_hash::sha1::mine::SHA1;
+    #[cfg(feature = "sha2")]
+    pub use crate::paranoid_hash::sha2::mine::{SHA2_224, SHA2_256, SHA2_384, SHA2_512};
+    #[cfg(feature = "sha3")]
+    pub use crate::paranoid_hash::sha3::mine::{SHA3_224, SHA3_256, SHA3_384, SHA3_512};
+}
+
+/// Implementations backed by the RustCrypto crates and crc32fast/crc32c.
+#[cfg(feature = "alter-impl")]
+pub mod rust_crypto {
+    #[cfg(feature = "crc32")]
+    pub use crate::paranoid_hash::crc32::alter::{CRC32, CRC32C};
+    #[cfg(feature = "md4")]
+    pub use crate::paranoid_hash::md4::alter::MD4;
+    #[cfg(feature = "md5")]
+    pub use crate::paranoid_hash::md5::alter::MD5;
+    #[cfg(feature = "sha1")]
+    pub use crate::paranoid_hash::sha1::alter::SHA1;
+    #[cfg(feature = "sha2")]
+    pub use crate::paranoid_hash::sha2::alter::{SHA2_224, SHA2_256, SHA2_384, SHA2_512};
+    #[cfg(feature = "sha3")]
+    pub use crate::paranoid_hash::sha3::alter::{SHA3_224, SHA3_256, SHA3_384, SHA3_512};
+}
+
+impl HasherTag {
+    /// Creates a hasher of the implementation from the backend.
+    ///
+    /// `Backend::RustCrypto` and `Backend::CrossCheck` fail with `Error::BackendUnavailable` if the
+    /// algorithm doesn't have a RustCrypto implementation, or the `alter-impl` feature is disabled.
+    pub fn new_hasher_with(self, backend: Backend) -> Result<Box<dyn Hasher>> {
+        match backend {
+            Backend::Native => Ok(native_hasher(self)),
+            Backend::RustCrypto => {
+                rust_crypto_hasher(self).ok_or(Error::BackendUnavailable(self, backend))
+            }
+            Backend::Fastest => Ok(fastest_hasher(self)),
+            Backend::CrossCheck => match rust_crypto_hasher(self) {
+                Some(hasher) => Ok(Box::new(CrossCheckHasher::new(
+                    native_hasher(self),
+                    hasher,
+                )?)),
+                None => Err(Error::BackendUnavailable(self, backend)),
+            },
+        }
+    }
+}
+
+fn native_hasher(tag: HasherTag) -> Box<dyn Hasher> {
+    match tag {
+        #[cfg(feature = "crc32")]
+        HasherTag::CRC32 => Box::new(native::CRC32::new()),
+        #[cfg(feature = "crc32")]
+        HasherTag::CRC32C => Box::new(native::CRC32C::new()),
+        #[cfg(feature = "md4")]
+        HasherTag::MD4 => Box::new(native::MD4::new()),
+        #[cfg(feature = "md5")]
+        HasherTag::MD5 => Box::new(native::MD5::new()),
+        #[cfg(feature = "sha1")]
+        HasherTag::SHA1 => Box::new(native::SHA1::new()),
+        #[cfg(feature = "sha2")]
+        HasherTag::SHA2_224 => Box::new(native::SHA2_224::new()),
+        #[cfg(feature = "sha2")]
+        HasherTag::SHA2_256 => Box::new(native::SHA2_256::new()),
+        #[cfg(feature = "sha2")]
+        HasherTag::SHA2_384 => Box::new(native::SHA2_384::new()),
+        #[cfg(feature = "sha2")]
+        HasherTag::SHA2_512 => Box::new(native::SHA2_512::new()),
+        #[cfg(feature = "sha3")]
+        HasherTag::SHA3_224 => Box::new(native::SHA3_224::new()),
+        #[cfg(feature = "sha3")]
+        HasherTag::SHA3_256 => Box::new(native::SHA3_256::new()),
+        #[cfg(feature = "sha3")]
+        HasherTag::SHA3_384 => Box::new(native::SHA3_384::new()),
+        #[cfg(feature = "sha3")]
+        HasherTag::SHA3_512 => Box::new(native::SHA3_512::new()),
+        // the rest only have native implementations
+        #[allow(unreachable_patterns)]
+        tag => tag.new_hasher(),
+    }
+}
+
+fn fastest_hasher(tag: HasherTag) -> Box<dyn Hasher> {
+    match tag {
+        // the RustCrypto ones can't save state, see Backend::RustCrypto
+        #[cfg(feature = "md4")]
+        HasherTag::MD4 => native_hasher(tag),
+        #[cfg(feature = "md5")]
+        HasherTag::MD5 => native_hasher(tag),
+        #[allow(unreachable_patterns)]
+        tag => rust_crypto_hasher(tag).unwrap_or_else(|| native_hasher(tag)),
+    }
+}
+
+#[cfg(feature = "alter-impl")]
+fn rust_crypto_hasher(tag: HasherTag) -> Option<Box<dyn Hasher>> {
+    match tag {
+        #[cfg(feature = "crc32")]
+        HasherTag::CRC32 => Some(Box::new(rust_crypto::CRC32::new())),
+        #[cfg(feature = "crc32")]
+        HasherTag::CRC32C => Some(Box::new(rust_crypto::CRC32C::new())),
+        #[cfg(feature = "md4")]
+        HasherTag::MD4 => Some(Box::new(rust_crypto::MD4::new())),
+        #[cfg(feature = "md5")]
+        HasherTag::MD5 => Some(Box::new(rust_crypto::MD5::new())),
+        #[cfg(feature = "sha1")]
+        HasherTag::SHA1 => Some(Box::new(rust_crypto::SHA1::new())),
+        #[cfg(feature = "sha2")]
+        HasherTag::SHA2_224 => Some(Box::new(rust_crypto::SHA2_224::new())),
+        #[cfg(feature = "sha2")]
+        HasherTag::SHA2_256 => Some(Box::new(rust_crypto::SHA2_256::new())),
+        #[cfg(feature = "sha2")]
+        HasherTag::SHA2_384 => Some(Box::new(rust_crypto::SHA2_384::new())),
+        #[cfg(feature = "sha2")]
+        HasherTag::SHA2_512 => Some(Box::new(rust_crypto::SHA2_512::new())),
+        #[cfg(feature = "sha3")]
+        HasherTag::SHA3_224 => Some(Box::new(rust_crypto::SHA3_224::new())),
+        #[cfg(feature = "sha3")]
+        HasherTag::SHA3_256 => Some(Box::new(rust_crypto::SHA3_256::new())),
+        #[cfg(feature = "sha3")]
+        HasherTag::SHA3_384 => Some(Box::new(rust_crypto::SHA3_384::new())),
+        #[cfg(feature = "sha3")]
+        HasherTag::SHA3_512 => Some(Box::new(rust_crypto::SHA3_512::new())),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+#[cfg(not(feature = "alter-impl"))]
+fn rust_crypto_hasher(_tag: HasherTag) -> Option<Box<dyn Hasher>> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        paranoid_hash::{Hasher, HasherTag},
+        Error,
+    };
+
+    use super::Backend;
+
+    fn hash(mut hasher: Box<dyn Hasher>) -> Vec<u8> {
+        let data = [0x61u8; 1000];
+        let split = data.len() / hasher.block_size() * hasher.block_size();
+        hasher.update(&data[..split]).unwrap();
+        hasher.update_last(&data[split..]).unwrap();
+        hasher.digest().unwrap().to_vec()
+    }
+
+    #[test]
+    fn backend_tests() {
+        let with_alter = [
+            #[cfg(feature = "crc32")]
+            HasherTag::CRC32,
+            #[cfg(feature = "crc32")]
+            HasherTag::CRC32C,
+            #[cfg(feature = "sha1")]
+            HasherTag::SHA1,
+            #[cfg(feature = "sha2")]
+            HasherTag::SHA2_224,
+            #[cfg(feature = "sha2")]
+            HasherTag::SHA2_256,
+            #[cfg(feature = "sha2")]
+            HasherTag::SHA2_384,
+            #[cfg(feature = "sha2")]
+            HasherTag::SHA2_512,
+            #[cfg(feature = "md4")]
+            HasherTag::MD4,
+            #[cfg(feature = "md5")]
+            HasherTag::MD5,
+            #[cfg(feature = "sha3")]
+            HasherTag::SHA3_224,
+            #[cfg(feature = "sha3")]
+            HasherTag::SHA3_256,
+            #[cfg(feature = "sha3")]
+            HasherTag::SHA3_384,
+            #[cfg(feature = "sha3")]
+            HasherTag::SHA3_512,
+        ];
+
+        for tag in HasherTag::all().iter().copied() {
+            let native = hash(tag.new_hasher_with(Backend::Native).unwrap());
+            assert_eq!(hash(tag.new_hasher_with(Backend::Fastest).unwrap()), native);
+            assert_eq!(hash(tag.new_hasher()), native);
+
+            match tag.new_hasher_with(Backend::RustCrypto) {
+                Ok(hasher) => {
+                    assert!(cfg!(feature = "alter-impl") && with_alter.contains(&tag));
+                    assert_eq!(hash(hasher), native);
+                }
+                Err(Error::BackendUnavailable(t, Backend::RustCrypto)) => {
+                    assert_eq!(t, tag);
+                    assert!(!cfg!(feature = "alter-impl") || !with_alter.contains(&tag));
+                }
+                Err(e) => panic!("{e}"),
+            }
+        }
+    }
+
+    #[test]
+    fn state_tests() {
+        // the default hashers can always save and restore state
+        for tag in HasherTag::all().iter().copied() {
+            assert!(tag.new_hasher().save_state().is_ok());
+            assert!(tag
+                .new_hasher_with(Backend::Fastest)
+                .unwrap()
+                .save_state()
+                .is_ok());
+        }
+
+        // states are interchangeable between backends
+        let tags: &[HasherTag] = &[
+            #[cfg(feature = "sha2")]
+            HasherTag::SHA2_256,
+            #[cfg(feature = "sha3")]
+            HasherTag::SHA3_256,
+        ];
+        for (tag, backend) in tags
+            .iter()
+            .flat_map(|tag| [(tag, Backend::Native), (tag, Backend::Fastest)])
+        {
+            let mut hasher = tag.new_hasher_with(backend).unwrap();
+            hasher.update(&vec![0x61; tag.block_size()]).unwrap();
+            let state = hasher.save_state().unwrap();
+
+            let mut restored = tag.new_hasher_with(Backend::Native).unwrap();
+            restored.restore_state(&state).unwrap();
+            restored.update_last("abc".as_bytes()).unwrap();
+            hasher.update_last("abc".as_bytes()).unwrap();
+            assert_eq!(restored.digest().unwrap(), hasher.digest().unwrap());
+        }
+    }
+}
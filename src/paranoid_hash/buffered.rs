@@ -0,0 +1,422 @@
+use alloc::vec::Vec;
+
+use crate::{
+    paranoid_hash::{
+        state::{StateReader, StateWriter},
+        Hasher,
+    },
+    Error, Result,
+};
+
+/// Buffers data for the underlying hasher, so `update` accepts slices of any length.
+///
+/// Whole blocks are passed to the hasher directly, the rest is kept until more data comes in or
+/// `finalize` is called.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BufferedHasher<H: Hasher> {
+    hasher: H,
+    buffer: Vec<u8>,
+    is_done: bool,
+}
+
+impl<H: Hasher> BufferedHasher<H> {
+    pub fn new(hasher: H) -> Self {
+        let buffer = Vec::with_capacity(hasher.block_size());
+        Self {
+            hasher,
+            buffer,
+            is_done: false,
+        }
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) -> Result<()> {
+        if self.is_done {
+            return Err(Error::UpdatingAfterFinished);
+        }
+
+        let block_size = self.hasher.block_size();
+
+        if !self.buffer.is_empty() {
+            let length = data.len().min(block_size - self.buffer.len());
+            self.buffer.extend_from_slice(&data[..length]);
+            data = &data[length..];
+
+            if self.buffer.len() < block_size {
+                return Ok(());
+            }
+
+            self.hasher.update(&self.buffer)?;
+            self.buffer.clear();
+        }
+
+        let length = data.len() / block_size * block_size;
+        if length != 0 {
+            self.hasher.update(&data[..length])?;
+        }
+
+        self.buffer.extend_from_slice(&data[length..]);
+
+        Ok(())
+    }
+
+    /// Hashes the buffered data and returns the digest, call `reset` before reusing the hasher.
+    pub fn finalize(&mut self) -> Result<&[u8]> {
+        if self.is_done {
+            return Err(Error::UpdatingAfterFinished);
+        }
+
+        // done even if it fails, the hasher may be half finished and can't take the data again
+        self.is_done = true;
+        let result = self.hasher.update_last(&self.buffer);
+        self.buffer.clear();
+        result?;
+        self.hasher.digest()
+    }
+
+    pub fn finalize_reset(&mut self) -> Result<Vec<u8>> {
+        let digest = self.finalize()?.to_vec();
+        self.reset();
+        Ok(digest)
+    }
+
+    pub fn digest(&self) -> Result<&[u8]> {
+        self.hasher.digest()
+    }
+
+    /// Returns the digest of the data so far, including the buffered data, without finalizing.
+    pub fn peek_digest(&self) -> Result<Vec<u8>> {
+        if self.is_done {
+            return Ok(self.hasher.digest()?.to_vec());
+        }
+
+        let mut hasher = self.hasher.box_clone()?;
+        hasher.update_last(&self.buffer)?;
+        Ok(hasher.digest()?.to_vec())
+    }
+
+    pub fn reset(&mut self) {
+        self.hasher.reset();
+        self.buffer.clear();
+        self.is_done = false;
+    }
+
+    pub fn block_size(&self) -> usize {
+        self.hasher.block_size()
+    }
+
+    pub fn digest_size(&self) -> usize {
+        self.hasher.digest_size()
+    }
+
+    /// Same as `Hasher::save_state`, the buffered data is saved as well.
+    pub fn save_state(&self) -> Result<Vec<u8>> {
+        let mut writer = StateWriter::new("BufferedHasher");
+        writer.write(&self.hasher.save_state()?);
+        writer.write(&self.buffer);
+        writer.write(&self.is_done);
+        Ok(writer.finish())
+    }
+
+    pub fn restore_state(&mut self, state: &[u8]) -> Result<()> {
+        let mut reader = StateReader::new(state, "BufferedHasher")?;
+        let hasher_state: Vec<u8> = reader.read()?;
+        let buffer: Vec<u8> = reader.read()?;
+        let is_done = reader.read()?;
+        reader.finish()?;
+
+        if buffer.len() >= self.hasher.block_size() {
+            return Err(Error::InvalidState("buffered data is longer than a block"));
+        }
+
+        self.hasher.restore_state(&hasher_state)?;
+        self.buffer = buffer;
+        self.is_done = is_done;
+        Ok(())
+    }
+
+    pub fn get_ref(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns the underlying hasher, the data still in the buffer is dropped.
+    pub fn into_inner(self) -> H {
+        self.hasher
+    }
+}
+
+impl<H: Hasher> From<H> for BufferedHasher<H> {
+    fn from(hasher: H) -> Self {
+        Self::new(hasher)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<H: Hasher> std::io::Write for BufferedHasher<H> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    #[cfg(feature = "has160")]
+    use crate::paranoid_hash::HAS160;
+    #[cfg(feature = "md2")]
+    use crate::paranoid_hash::MD2;
+    #[cfg(feature = "md4")]
+    use crate::paranoid_hash::MD4;
+    #[cfg(feature = "md5")]
+    use crate::paranoid_hash::MD5;
+    #[cfg(feature = "sha1")]
+    use crate::paranoid_hash::SHA1;
+    #[cfg(feature = "sha3")]
+    use crate::paranoid_hash::SHA3_256;
+    #[cfg(feature = "crc32")]
+    use crate::paranoid_hash::{CRC32, CRC32C};
+    #[cfg(feature = "sha2")]
+    use crate::paranoid_hash::{SHA2_224, SHA2_256, SHA2_512};
+    use crate::{paranoid_hash::Hasher, Error, Result};
+
+    use super::BufferedHasher;
+
+    const DATA: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+
+    fn hash_in_chunks<H: Hasher>(hasher: H, chunk_size: usize, repeat: usize) -> String {
+        let mut hasher = BufferedHasher::new(hasher);
+        for _ in 0..repeat {
+            for chunk in DATA.chunks(chunk_size) {
+                hasher.update(chunk).unwrap();
+            }
+        }
+        hex::encode(hasher.finalize().unwrap())
+    }
+
+    #[test]
+    fn chunk_tests() {
+        for chunk_size in [1, 7, 64, 200] {
+            #[cfg(feature = "md2")]
+            assert_eq!(
+                hash_in_chunks(MD2::new(), chunk_size, 1),
+                "03d85a0d629d2c442e987525319fc471"
+            );
+            #[cfg(feature = "md5")]
+            assert_eq!(
+                hash_in_chunks(MD5::new(), chunk_size, 1),
+                "9e107d9d372bb6826bd81d3542a419d6"
+            );
+            #[cfg(feature = "sha1")]
+            assert_eq!(
+                hash_in_chunks(SHA1::new(), chunk_size, 1),
+                "2fd4e1c67a2d28fced849ee1bb76e7391b93eb12"
+            );
+            #[cfg(feature = "sha1")]
+            assert_eq!(
+                hash_in_chunks(SHA1::new(), chunk_size, 10),
+                hash_in_chunks(SHA1::new(), DATA.len() * 10, 10)
+            );
+            #[cfg(feature = "sha2")]
+            assert_eq!(
+                hash_in_chunks(SHA2_512::new(), chunk_size, 10),
+                hash_in_chunks(SHA2_512::new(), DATA.len() * 10, 10)
+            );
+            #[cfg(feature = "sha3")]
+            assert_eq!(
+                hash_in_chunks(SHA3_256::new(), chunk_size, 1),
+                "69070dda01975c8c120c3aada1b282394e7f032fa9cf32f4cb2259a0897dfc04"
+            );
+            #[cfg(feature = "crc32")]
+            assert_eq!(hash_in_chunks(CRC32::new(), chunk_size, 1), "414fa339");
+        }
+    }
+
+    #[test]
+    fn default_test() {
+        fn assert_default<H: Hasher + Default>(hasher: H) {
+            assert_eq!(
+                hash_in_chunks(H::default(), 7, 1),
+                hash_in_chunks(hasher, 7, 1)
+            );
+        }
+
+        #[cfg(feature = "crc32")]
+        assert_default(CRC32::new());
+        #[cfg(feature = "crc32")]
+        assert_default(CRC32C::new());
+        #[cfg(feature = "md4")]
+        assert_default(MD4::new());
+        #[cfg(feature = "md5")]
+        assert_default(MD5::new());
+        #[cfg(feature = "sha1")]
+        assert_default(SHA1::new());
+        #[cfg(feature = "sha2")]
+        assert_default(SHA2_224::new());
+        #[cfg(feature = "sha2")]
+        assert_default(SHA2_256::new());
+        #[cfg(feature = "has160")]
+        assert_default(HAS160::new());
+    }
+
+    #[cfg(feature = "sha1")]
+    #[test]
+    fn boxed_test() {
+        let hasher: Box<dyn Hasher> = Box::new(SHA1::new());
+        assert_eq!(
+            hash_in_chunks(hasher, 5, 1),
+            "2fd4e1c67a2d28fced849ee1bb76e7391b93eb12"
+        );
+    }
+
+    #[cfg(all(feature = "std", feature = "sha1"))]
+    #[test]
+    fn write_test() {
+        use std::io::Write;
+
+        let mut hasher = BufferedHasher::new(SHA1::new());
+        std::io::copy(&mut DATA.repeat(10).as_slice(), &mut hasher).unwrap();
+        assert_eq!(
+            hex::encode(hasher.finalize().unwrap()),
+            hash_in_chunks(SHA1::new(), DATA.len(), 10)
+        );
+
+        let error = hasher.write(DATA).unwrap_err();
+        assert!(matches!(
+            error.into_inner().unwrap().downcast::<Error>().as_deref(),
+            Ok(Error::UpdatingAfterFinished)
+        ));
+    }
+
+    #[cfg(feature = "md5")]
+    #[test]
+    fn reset_test() {
+        let mut hasher = BufferedHasher::new(MD5::new());
+        hasher.update(&DATA[..10]).unwrap();
+        hasher.reset();
+        hasher.update(DATA).unwrap();
+        assert_eq!(
+            hex::encode(hasher.finalize_reset().unwrap()),
+            "9e107d9d372bb6826bd81d3542a419d6"
+        );
+
+        hasher.update(&[]).unwrap();
+        assert_eq!(
+            hex::encode(hasher.finalize().unwrap()),
+            "d41d8cd98f00b204e9800998ecf8427e"
+        );
+        assert!(hasher.update(DATA).is_err());
+        assert!(hasher.finalize().is_err());
+    }
+
+    #[test]
+    fn peek_test() {
+        let hashers: Vec<Box<dyn Hasher>> = vec![
+            #[cfg(feature = "crc32")]
+            Box::new(CRC32::new()),
+            #[cfg(feature = "md2")]
+            Box::new(MD2::new()),
+            #[cfg(feature = "sha1")]
+            Box::new(SHA1::new()),
+            #[cfg(feature = "sha2")]
+            Box::new(SHA2_512::new()),
+            #[cfg(feature = "sha3")]
+            Box::new(SHA3_256::new()),
+        ];
+
+        for hasher in hashers {
+            let hash = |data: &[u8]| {
+                let mut hasher = BufferedHasher::new(hasher.box_clone().unwrap());
+                hasher.update(data).unwrap();
+                hasher.finalize().unwrap().to_vec()
+            };
+            let (prefix, full) = (hash(&DATA[..20]), hash(DATA));
+
+            let mut hasher = BufferedHasher::new(hasher.box_clone().unwrap());
+            hasher.update(&DATA[..20]).unwrap();
+            assert_eq!(hasher.peek_digest().unwrap(), prefix);
+
+            hasher.update(&DATA[20..]).unwrap();
+            assert_eq!(hasher.peek_digest().unwrap(), full);
+            assert_eq!(hasher.finalize().unwrap(), full);
+            assert_eq!(hasher.peek_digest().unwrap(), full);
+        }
+    }
+
+    // counts the last updates, which always fail
+    #[derive(Default)]
+    struct FailingHasher {
+        last_updates: usize,
+    }
+
+    impl Hasher for FailingHasher {
+        fn update(&mut self, _data: &[u8]) -> Result<()> {
+            Ok(())
+        }
+
+        fn update_last(&mut self, _data: &[u8]) -> Result<()> {
+            self.last_updates += 1;
+            Err(Error::InvalidState("failed on purpose"))
+        }
+
+        fn digest(&self) -> Result<&[u8]> {
+            Err(Error::NotFinished)
+        }
+
+        fn reset(&mut self) {
+            self.last_updates = 0;
+        }
+
+        fn block_size(&self) -> usize {
+            4
+        }
+
+        fn digest_size(&self) -> usize {
+            4
+        }
+    }
+
+    #[test]
+    fn failed_finalize_test() {
+        let mut hasher = BufferedHasher::new(FailingHasher::default());
+        hasher.update(&DATA[..6]).unwrap();
+        assert!(matches!(hasher.finalize(), Err(Error::InvalidState(_))));
+
+        // the half finished hasher doesn't get the data again
+        assert!(matches!(
+            hasher.finalize(),
+            Err(Error::UpdatingAfterFinished)
+        ));
+        assert!(matches!(
+            hasher.update(DATA),
+            Err(Error::UpdatingAfterFinished)
+        ));
+        assert_eq!(hasher.get_ref().last_updates, 1);
+
+        hasher.reset();
+        hasher.update(DATA).unwrap();
+        assert!(hasher.finalize().is_err());
+        assert_eq!(hasher.get_ref().last_updates, 1);
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn fork_test() {
+        let mut common = SHA2_256::new();
+        common.update(&[0; SHA2_256::BLOCK_SIZE]).unwrap();
+
+        let mut fork = common.box_clone().unwrap();
+        common.update_last(&DATA[..10]).unwrap();
+        fork.update_last(DATA).unwrap();
+
+        let mut expected = BufferedHasher::new(SHA2_256::new());
+        expected.update(&[0; SHA2_256::BLOCK_SIZE]).unwrap();
+        expected.update(DATA).unwrap();
+        assert_eq!(fork.digest().unwrap(), expected.finalize().unwrap());
+        assert_ne!(fork.digest().unwrap(), common.digest().unwrap());
+    }
+}
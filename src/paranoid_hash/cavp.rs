@@ -0,0 +1,557 @@
+//! Runs NIST CAVP response files (SHAVS and SHA3VS) against the built-in hashers.
+//!
+//! The ShortMsg, LongMsg and Monte Carlo files are supported, both byte and bit oriented. Messages
+//! which aren't whole bytes are hashed by `BitHasher::update_last_bits`. The official vectors are
+//! in the `cavp` directory of the repository.
+
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use core::fmt;
+
+use crate::{
+    paranoid_hash::{Backend, BitHasher, Digest, Hasher, HasherTag},
+    Error, Result,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageTest {
+    /// Only the first `bit_len` bits are hashed, it's `bit_len` rounded up to bytes long.
+    pub message: Vec<u8>,
+    pub bit_len: usize,
+    pub digest: Digest,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MonteCarloTest {
+    pub seed: Vec<u8>,
+    /// Expected digest of every checkpoint, indexed by COUNT.
+    pub digests: Vec<Digest>,
+}
+
+/// Tests of a `.rsp` file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CavpFile {
+    pub messages: Vec<MessageTest>,
+    pub monte_carlo: Option<MonteCarloTest>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CavpFailure {
+    /// "Len = 8" for message tests, "COUNT = 3" for Monte Carlo checkpoints.
+    pub name: String,
+    pub expected: Digest,
+    pub actual: Digest,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CavpReport {
+    pub passed: usize,
+    pub failures: Vec<CavpFailure>,
+    /// Messages which aren't whole bytes, when the implementation can't hash them.
+    pub skipped: usize,
+}
+
+impl CavpReport {
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    fn check(&mut self, name: impl FnOnce() -> String, expected: &Digest, actual: &[u8]) {
+        if *expected == actual {
+            self.passed += 1;
+            return;
+        }
+
+        self.failures.push(CavpFailure {
+            name: name(),
+            expected: *expected,
+            actual: Digest::new(actual).expect("digest is never longer than 64 bytes"),
+        });
+    }
+}
+
+impl fmt::Display for CavpReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} skipped",
+            self.passed,
+            self.failures.len(),
+            self.skipped
+        )?;
+        for failure in &self.failures {
+            write!(
+                f,
+                "\n{}: expected {}, got {}",
+                failure.name, failure.expected, failure.actual
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl CavpFile {
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut file = Self::default();
+        let mut length: Option<usize> = None;
+        let mut message = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let error = |reason| Error::InvalidTestVectors(line_number, reason);
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('[') {
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or(error("expected key = value"))?;
+            let value = value.trim();
+            match key.trim() {
+                "Len" => {
+                    length = Some(value.parse().map_err(|_| error("invalid length"))?);
+                }
+                "Msg" => message = Some(decode_hex(value).ok_or(error("invalid hex"))?),
+                "Seed" => {
+                    file.monte_carlo = Some(MonteCarloTest {
+                        seed: decode_hex(value).ok_or(error("invalid hex"))?,
+                        digests: Vec::new(),
+                    })
+                }
+                "COUNT" => {
+                    let count: usize = value.parse().map_err(|_| error("invalid count"))?;
+
+                    // SHA3VS Monte Carlo files give the seed as a message without length
+                    if file.monte_carlo.is_none() && length.is_none() {
+                        if let Some(seed) = message.take() {
+                            file.monte_carlo = Some(MonteCarloTest {
+                                seed,
+                                digests: Vec::new(),
+                            });
+                        }
+                    }
+
+                    let monte_carlo = file.monte_carlo.as_ref().ok_or(error("missing seed"))?;
+                    if count != monte_carlo.digests.len() {
+                        return Err(error("count out of order"));
+                    }
+                }
+                "MD" => {
+                    let digest = decode_hex(value)
+                        .and_then(|digest| Digest::new(&digest).ok())
+                        .ok_or(error("invalid digest"))?;
+
+                    if let Some(monte_carlo) = file.monte_carlo.as_mut() {
+                        monte_carlo.digests.push(digest);
+                        continue;
+                    }
+
+                    let (Some(bit_len), Some(mut message)) = (length.take(), message.take()) else {
+                        return Err(error("missing length or message"));
+                    };
+
+                    // the empty message is written as "00"
+                    if message.len() < bit_len.div_ceil(8) {
+                        return Err(error("message is shorter than its length"));
+                    }
+                    message.truncate(bit_len.div_ceil(8));
+
+                    file.messages.push(MessageTest {
+                        message,
+                        bit_len,
+                        digest,
+                    });
+                }
+                _ => return Err(error("unknown key")),
+            }
+        }
+
+        Ok(file)
+    }
+
+    /// Runs the tests with the default implementation of the algorithm.
+    pub fn run(&self, tag: HasherTag) -> Result<CavpReport> {
+        self.run_with(tag, Backend::Fastest)
+    }
+
+    /// Runs the tests with the implementation from the backend.
+    ///
+    /// The Monte Carlo test of SHA-3 differs from the one of SHA-1 and SHA-2, the tag decides which
+    /// one is used. Messages which aren't whole bytes are skipped if the implementation isn't a
+    /// `BitHasher` or returns `Error::PartialBitsUnsupported`.
+    pub fn run_with(&self, tag: HasherTag, backend: Backend) -> Result<CavpReport> {
+        let mut hasher = tag.new_hasher_with(backend)?;
+        let mut bit_hasher = new_bit_hasher(tag, backend);
+        let mut report = CavpReport::default();
+
+        for test in &self.messages {
+            let actual = if test.bit_len.is_multiple_of(8) {
+                hash(hasher.as_mut(), &test.message)?
+            } else {
+                let Some(bit_hasher) = bit_hasher.as_mut() else {
+                    report.skipped += 1;
+                    continue;
+                };
+
+                match hash_bits(bit_hasher.as_mut(), &test.message, test.bit_len) {
+                    Err(Error::PartialBitsUnsupported) => {
+                        report.skipped += 1;
+                        continue;
+                    }
+                    result => result?,
+                }
+            };
+
+            report.check(|| format!("Len = {}", test.bit_len), &test.digest, &actual);
+        }
+
+        let Some(monte_carlo) = &self.monte_carlo else {
+            return Ok(report);
+        };
+
+        let is_sha3 = match tag {
+            #[cfg(feature = "sha3")]
+            HasherTag::SHA3_224
+            | HasherTag::SHA3_256
+            | HasherTag::SHA3_384
+            | HasherTag::SHA3_512 => true,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        };
+        let mut seed = monte_carlo.seed.clone();
+        for (count, expected) in monte_carlo.digests.iter().enumerate() {
+            seed = if is_sha3 {
+                sha3_monte_carlo(hasher.as_mut(), seed)?
+            } else {
+                sha_monte_carlo(hasher.as_mut(), seed)?
+            };
+
+            report.check(|| format!("COUNT = {count}"), expected, &seed);
+        }
+
+        Ok(report)
+    }
+}
+
+// SHAVS 6.4, every digest is computed from the previous three.
+fn sha_monte_carlo(hasher: &mut dyn Hasher, seed: Vec<u8>) -> Result<Vec<u8>> {
+    let mut digests = [seed.clone(), seed.clone(), seed];
+    for _ in 0..1000 {
+        let next = hash(hasher, &digests.concat())?;
+        digests.rotate_left(1);
+        digests[2] = next;
+    }
+
+    let [_, _, last] = digests;
+    Ok(last)
+}
+
+// SHA3VS 6.2.3, every digest is computed from the previous one.
+fn sha3_monte_carlo(hasher: &mut dyn Hasher, seed: Vec<u8>) -> Result<Vec<u8>> {
+    let mut digest = seed;
+    for _ in 0..1000 {
+        digest = hash(hasher, &digest)?;
+    }
+
+    Ok(digest)
+}
+
+fn hash(hasher: &mut dyn Hasher, data: &[u8]) -> Result<Vec<u8>> {
+    hasher.reset();
+
+    let separator = data.len() / hasher.block_size() * hasher.block_size();
+    hasher.update(&data[..separator])?;
+    hasher.update_last(&data[separator..])?;
+
+    Ok(hasher.digest()?.to_vec())
+}
+
+fn hash_bits(hasher: &mut dyn BitHasher, data: &[u8], bit_len: usize) -> Result<Vec<u8>> {
+    hasher.reset();
+
+    let separator = (bit_len / 8) / hasher.block_size() * hasher.block_size();
+    hasher.update(&data[..separator])?;
+    hasher.update_last_bits(&data[separator..], bit_len - separator * 8)?;
+
+    Ok(hasher.digest()?.to_vec())
+}
+
+// The algorithms with bit oriented vectors, from the module of the backend.
+macro_rules! bit_hasher {
+    ( $tag:expr, $( $module:ident )::+ ) => {
+        match $tag {
+            #[cfg(feature = "sha1")]
+            HasherTag::SHA1 => {
+                Some(Box::new($( $module:: )+SHA1::new()) as Box<dyn BitHasher>)
+            }
+            #[cfg(feature = "sha2")]
+            HasherTag::SHA2_224 => {
+                Some(Box::new($( $module:: )+SHA2_224::new()) as Box<dyn BitHasher>)
+            }
+            #[cfg(feature = "sha2")]
+            HasherTag::SHA2_256 => {
+                Some(Box::new($( $module:: )+SHA2_256::new()) as Box<dyn BitHasher>)
+            }
+            #[cfg(feature = "sha2")]
+            HasherTag::SHA2_384 => {
+                Some(Box::new($( $module:: )+SHA2_384::new()) as Box<dyn BitHasher>)
+            }
+            #[cfg(feature = "sha2")]
+            HasherTag::SHA2_512 => {
+                Some(Box::new($( $module:: )+SHA2_512::new()) as Box<dyn BitHasher>)
+            }
+            #[cfg(feature = "sha3")]
+            HasherTag::SHA3_224 => {
+                Some(Box::new($( $module:: )+SHA3_224::new()) as Box<dyn BitHasher>)
+            }
+            #[cfg(feature = "sha3")]
+            HasherTag::SHA3_256 => {
+                Some(Box::new($( $module:: )+SHA3_256::new()) as Box<dyn BitHasher>)
+            }
+            #[cfg(feature = "sha3")]
+            HasherTag::SHA3_384 => {
+                Some(Box::new($( $module:: )+SHA3_384::new()) as Box<dyn BitHasher>)
+            }
+            #[cfg(feature = "sha3")]
+            HasherTag::SHA3_512 => {
+                Some(Box::new($( $module:: )+SHA3_512::new()) as Box<dyn BitHasher>)
+            }
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    };
+}
+
+fn new_bit_hasher(tag: HasherTag, backend: Backend) -> Option<Box<dyn BitHasher>> {
+    match backend {
+        Backend::Native => bit_hasher!(tag, crate::paranoid_hash::native),
+        #[cfg(feature = "alter-impl")]
+        Backend::RustCrypto => bit_hasher!(tag, crate::paranoid_hash::rust_crypto),
+        // the default types, which are the ones Backend::Fastest picks
+        Backend::Fastest => bit_hasher!(tag, crate::paranoid_hash),
+        _ => None,
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+
+    hex.as_bytes()
+        .chunks_exact(2)
+        .map(|pair| u8::from_str_radix(core::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    use std::{fs, path::Path};
+
+    use crate::{
+        paranoid_hash::{Backend, Digest, HasherTag},
+        Error,
+    };
+
+    use super::CavpFile;
+
+    const SHORT_MSG: &str = "#  \"SHA-1 ShortMsg\" information
+
+[L = 20]
+
+Len = 0
+Msg = 00
+MD = da39a3ee5e6b4b0d3255bfef95601890afd80709
+
+Len = 24
+Msg = 616263
+MD = a9993e364706816aba3e25717850c26c9cd0d89d
+";
+
+    #[test]
+    fn parse_tests() {
+        let file = CavpFile::parse(SHORT_MSG).unwrap();
+        assert_eq!(file.messages.len(), 2);
+        assert!(file.messages[0].message.is_empty());
+        assert_eq!(file.messages[1].message, "abc".as_bytes());
+        assert!(file.monte_carlo.is_none());
+
+        #[cfg(feature = "sha1")]
+        {
+            let report = file.run(HasherTag::SHA1).unwrap();
+            assert!(report.is_success());
+            assert_eq!(report.passed, 2);
+        }
+
+        #[cfg(feature = "sha1dc")]
+        assert_eq!(file.run(HasherTag::SHA1DC).unwrap().passed, 2);
+
+        #[cfg(feature = "md5")]
+        {
+            let report = file.run(HasherTag::MD5).unwrap();
+            assert_eq!(report.passed, 0);
+            assert_eq!(report.failures[1].name, "Len = 24");
+            assert_eq!(
+                report.failures[1].actual,
+                "900150983cd24fb0d6963f7d28e17f72"
+                    .parse::<Digest>()
+                    .unwrap()
+            );
+        }
+
+        let invalid = [
+            ("Len = 8\nMsg = 0g\nMD = 00", 2),
+            ("Len = 16\nMsg = 00\nMD = 00", 3),
+            ("Msg = 00\nMD = 00", 2),
+            ("Seed = 00\nCOUNT = 1\nMD = 00", 2),
+            // a message with a length is not a seed
+            ("Len = 8\nMsg = 00\nCOUNT = 0\nMD = 00", 3),
+            ("Len 8", 1),
+            ("Outputlen = 8", 1),
+        ];
+        for (text, line) in invalid {
+            match CavpFile::parse(text) {
+                Err(Error::InvalidTestVectors(l, _)) => assert_eq!(l, line, "{text}"),
+                result => panic!("{result:?}"),
+            }
+        }
+    }
+
+    // SHA3VS layout, the digests are from hashlib.sha3_256
+    const SHA3_MONTE: &str = "#  \"SHA3-256 Monte\" information
+
+[L = 256]
+
+Msg = aa64f7245e2177c654eb4de360da8761a516fdc7578c3498c5e582e096b8730c
+
+COUNT = 0
+MD = 225cbac2be6f329d94228c5360a1c177bc495a761c442a1771b1d18555c309a5
+
+COUNT = 1
+MD = 96d364a1b1ced3dbbce6380093fb1ac77221abcee30faf16546ffad8fe1eef8c
+";
+
+    #[test]
+    fn sha3_monte_carlo_tests() {
+        let file = CavpFile::parse(SHA3_MONTE).unwrap();
+        assert!(file.messages.is_empty());
+        let monte_carlo = file.monte_carlo.as_ref().unwrap();
+        assert_eq!(monte_carlo.seed.len(), 32);
+        assert_eq!(monte_carlo.digests.len(), 2);
+
+        #[cfg(feature = "sha3")]
+        {
+            let report = file.run(HasherTag::SHA3_256).unwrap();
+            assert!(report.is_success(), "{report}");
+            assert_eq!(report.passed, 2);
+        }
+    }
+
+    #[test]
+    fn bit_tests() {
+        let file = CavpFile::parse(
+            "Len = 5\nMsg = 98\nMD = 29826b003b906e660eff4027ce98af3531ac75ba\n\n\
+             Len = 24\nMsg = 616263\nMD = a9993e364706816aba3e25717850c26c9cd0d89d",
+        )
+        .unwrap();
+        assert_eq!(file.messages[0].bit_len, 5);
+        assert_eq!(file.messages[0].message, [0x98]);
+
+        #[cfg(feature = "sha1")]
+        {
+            let report = file.run_with(HasherTag::SHA1, Backend::Native).unwrap();
+            assert_eq!((report.passed, report.skipped), (2, 0));
+            assert!(report.is_success());
+
+            // the RustCrypto one only hashes whole bytes
+            if let Ok(report) = file.run_with(HasherTag::SHA1, Backend::RustCrypto) {
+                assert_eq!((report.passed, report.skipped), (1, 1));
+            }
+        }
+
+        // not a BitHasher
+        #[cfg(feature = "md5")]
+        {
+            let report = file.run(HasherTag::MD5).unwrap();
+            assert_eq!((report.passed, report.skipped), (0, 1));
+        }
+
+        // the bits of the last byte are the least significant ones for SHA-3
+        #[cfg(feature = "sha3")]
+        {
+            let file = CavpFile::parse(
+                "Len = 5\nMsg = 13\n\
+                 MD = 7b0047cf5a456882363cbf0fb05322cf65f4b7059a46365e830132e3b5d957af",
+            )
+            .unwrap();
+            let report = file.run(HasherTag::SHA3_256).unwrap();
+            assert_eq!((report.passed, report.skipped), (1, 0));
+        }
+    }
+
+    // Runs the ShortMsg, LongMsg and Monte Carlo files of every algorithm, with their NIST names,
+    // on every backend. Files which are missing or ran no test fail.
+    fn run_nist_files(directory: &str, algorithms: &[(&str, HasherTag)]) {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("cavp")
+            .join(directory);
+
+        for (algorithm, tag) in algorithms {
+            for kind in ["ShortMsg", "LongMsg", "Monte"] {
+                let name = format!("{algorithm}{kind}.rsp");
+                let text = fs::read_to_string(root.join(&name))
+                    .unwrap_or_else(|e| panic!("{directory}/{name}: {e}"));
+                let file = CavpFile::parse(&text).unwrap();
+
+                for backend in [Backend::Native, Backend::RustCrypto] {
+                    let report = match file.run_with(*tag, backend) {
+                        Err(Error::BackendUnavailable(..)) => continue,
+                        report => report.unwrap(),
+                    };
+
+                    assert!(report.is_success(), "{name} {backend:?}: {report}");
+                    assert!(report.passed > 0, "{name} {backend:?}: {report}");
+                    if backend == Backend::Native {
+                        assert_eq!(report.skipped, 0, "{name}: {report}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nist_tests() {
+        run_nist_files(
+            "SHAVS",
+            &[
+                #[cfg(feature = "sha1")]
+                ("SHA1", HasherTag::SHA1),
+                #[cfg(feature = "sha2")]
+                ("SHA224", HasherTag::SHA2_224),
+                #[cfg(feature = "sha2")]
+                ("SHA256", HasherTag::SHA2_256),
+                #[cfg(feature = "sha2")]
+                ("SHA384", HasherTag::SHA2_384),
+                #[cfg(feature = "sha2")]
+                ("SHA512", HasherTag::SHA2_512),
+            ],
+        );
+    }
+
+    #[cfg(feature = "sha3")]
+    #[test]
+    #[ignore = "needs the SHA3VS response files in cavp/SHA3VS, see cavp/README.md"]
+    fn nist_sha3_tests() {
+        run_nist_files(
+            "SHA3VS",
+            &[
+                ("SHA3_224", HasherTag::SHA3_224),
+                ("SHA3_256", HasherTag::SHA3_256),
+                ("SHA3_384", HasherTag::SHA3_384),
+                ("SHA3_512", HasherTag::SHA3_512),
+            ],
+        );
+    }
+}
@@ -1,4 +1,6 @@
 #![macro_use]
+// Shared by the algorithm families, some of them are unused when only a few are enabled
+#![cfg_attr(not(feature = "all-algorithms"), allow(unused_macros))]
 
 macro_rules! add {
     ("wrapping", $left:expr, $right:expr, $count_type:ty) => {
@@ -12,6 +14,17 @@ macro_rules! add {
     };
 }
 
+macro_rules! add_bits {
+    ("wrapping", $left:expr, $right:expr, $count_type:ty) => {
+        $left = $left.wrapping_add($right);
+    };
+    ("checked", $left:expr, $right:expr, $count_type:ty) => {
+        $left = $left
+            .checked_add($right)
+            .ok_or(Error::DataLengthOverflowed(<$count_type>::MAX as u128))?;
+    };
+}
+
 macro_rules! from_bytes {
     ("le", $word_type:ty, $data:expr) => {
         <$word_type>::from_le_bytes($data)
@@ -39,7 +52,7 @@ macro_rules! transmute_update {
             return Err(Error::UpdatingAfterFinished);
         }
 
-        if $data.len() % $block_size != 0 {
+        if !$data.len().is_multiple_of($block_size) {
             return Err(Error::DataLengthMismatched($data.len(), $block_size));
         }
 
@@ -72,6 +85,24 @@ macro_rules! transmute_update {
 #[macro_export]
 macro_rules! transmute_update_last {
     ( $self:expr, $data:expr, $block_size:expr, $word_type:ty, $count_type:ty, $add_mode:tt, $endian:tt ) => {
+        transmute_update_last_bits!(
+            $self,
+            $data,
+            $data.len() * 8,
+            $block_size,
+            $word_type,
+            $count_type,
+            $add_mode,
+            $endian
+        );
+    };
+}
+
+/// Same as `transmute_update_last`, but only the first `bit_len` bits of data are hashed, the
+/// bits of the last partial byte are taken from its most significant end.
+#[macro_export]
+macro_rules! transmute_update_last_bits {
+    ( $self:expr, $data:expr, $bit_len:expr, $block_size:expr, $word_type:ty, $count_type:ty, $add_mode:tt, $endian:tt ) => {
         const PADDING: u8 = 0b1000_0000;
         const PADDING_LENGTH: usize = 1;
 
@@ -85,20 +116,32 @@ macro_rules! transmute_update_last {
             return Err(Error::DataTooLarge($data.len(), $block_size));
         }
 
-        add!(
+        let bit_len: usize = $bit_len;
+        if $data.len() != bit_len.div_ceil(8) {
+            return Err(Error::BitLengthMismatched(bit_len, $data.len()));
+        }
+
+        let byte_len = bit_len / 8;
+        let extra_bits = bit_len % 8;
+
+        add!($add_mode, $self.count, byte_len as $count_type, $count_type);
+        add_bits!(
             $add_mode,
             $self.count,
-            $data.len() as $count_type,
+            extra_bits as $count_type,
             $count_type
         );
 
         let mut final_block = [0u8; $block_size * 2];
-        final_block[0..$data.len()].clone_from_slice($data);
-        final_block[$data.len()] = PADDING;
+        final_block[0..byte_len].clone_from_slice(&$data[..byte_len]);
+        final_block[byte_len] = PADDING >> extra_bits;
+        if extra_bits != 0 {
+            final_block[byte_len] |= $data[byte_len] & !(0xff >> extra_bits);
+        }
 
         let count = to_bytes!($endian, $self.count);
 
-        let final_block_slice = if $data.len() + count.len() + PADDING_LENGTH <= $block_size {
+        let final_block_slice = if byte_len + count.len() + PADDING_LENGTH <= $block_size {
             final_block[$block_size - count.len()..$block_size].clone_from_slice(&count);
             &final_block[0..$block_size]
         } else {
@@ -109,7 +152,7 @@ macro_rules! transmute_update_last {
 
         $self.update(final_block_slice)?;
 
-        let digest_chunks: &mut [[u8; std::mem::size_of::<$word_type>()]] =
+        let digest_chunks: &mut [[u8; core::mem::size_of::<$word_type>()]] =
             $crate::paranoid_hash::hash_helper::slice_as_chunks_mut(&mut $self.digest);
         for (d, s) in digest_chunks.iter_mut().zip($self.state.iter()) {
             d.clone_from_slice(&to_bytes!($endian, s));
@@ -120,3 +163,138 @@ macro_rules! transmute_update_last {
         return Ok(());
     };
 }
+
+/// Implements `save_state` and `restore_state` by saving the listed fields in order.
+macro_rules! impl_state {
+    ( $name:expr, $( $field:ident ),+ ) => {
+        fn save_state(&self) -> $crate::Result<::alloc::vec::Vec<u8>> {
+            let mut writer = $crate::paranoid_hash::state::StateWriter::new($name);
+            $( writer.write(&self.$field); )+
+            Ok(writer.finish())
+        }
+
+        fn restore_state(&mut self, state: &[u8]) -> $crate::Result<()> {
+            let mut reader = $crate::paranoid_hash::state::StateReader::new(state, $name)?;
+            $( let $field = reader.read()?; )+
+            reader.finish()?;
+            $( self.$field = $field; )+
+            Ok(())
+        }
+    };
+}
+
+/// Implements `box_clone` for hashers which implement `Clone`.
+macro_rules! impl_box_clone {
+    () => {
+        fn box_clone(
+            &self,
+        ) -> $crate::Result<::alloc::boxed::Box<dyn $crate::paranoid_hash::Hasher>> {
+            Ok(::alloc::boxed::Box::new(self.clone()))
+        }
+    };
+}
+
+/// Feeds all of `data` and its padding to the const `update_block` of a fresh hasher, for the
+/// one-shot hashing in const contexts. `$read` is `read_u32_const` or `read_u64_const`.
+macro_rules! md_hash_const {
+    ( $hasher:expr, $data:expr, $block_size:expr, $read:ident, $length_size:expr, $big_endian:expr ) => {{
+        use $crate::paranoid_hash::hash_helper::{md_padding_const, $read};
+
+        let mut hasher = $hasher;
+        let full_blocks = $data.len() - $data.len() % $block_size;
+        let mut offset = 0;
+        while offset < full_blocks {
+            hasher.update_block(&$read($data, offset, $big_endian));
+            offset += $block_size;
+        }
+
+        let (padded, length) = md_padding_const($data, $block_size, $length_size, $big_endian);
+        let mut offset = 0;
+        while offset < length {
+            hasher.update_block(&$read(&padded, offset, $big_endian));
+            offset += $block_size;
+        }
+
+        hasher
+    }};
+}
+
+/// Defines a hasher on top of one from the RustCrypto crates, which hashes with their own
+/// `update` and `finalize`. These crates keep their states private, so saving and restoring state
+/// isn't supported. `$krate` is the crate and `$base` the hasher type in it.
+#[cfg(feature = "alter-impl")]
+macro_rules! create_rust_crypto_hasher {
+    ( $struct:ident, $krate:ident, $base:ident, $block_size:expr, $digest_size:expr ) => {
+        #[derive(Clone, Debug)]
+        pub struct $struct {
+            state: $krate::$base,
+            is_done: bool,
+            digest: [u8; $digest_size],
+        }
+
+        impl $crate::paranoid_hash::Hasher for $struct {
+            fn update(&mut self, data: &[u8]) -> $crate::Result<()> {
+                if self.is_done {
+                    return Err($crate::Error::UpdatingAfterFinished);
+                }
+
+                $krate::Digest::update(&mut self.state, data);
+                Ok(())
+            }
+
+            fn update_last(&mut self, data: &[u8]) -> $crate::Result<()> {
+                if self.is_done {
+                    return Err($crate::Error::UpdatingAfterFinished);
+                }
+
+                self.is_done = true;
+
+                $krate::Digest::update(&mut self.state, data);
+                let digest = $krate::Digest::finalize(self.state.clone());
+                self.digest.copy_from_slice(&digest[..]);
+                Ok(())
+            }
+
+            fn digest(&self) -> $crate::Result<&[u8]> {
+                if !self.is_done {
+                    return Err($crate::Error::NotFinished);
+                }
+
+                Ok(&self.digest)
+            }
+
+            fn reset(&mut self) {
+                *self = Self::new();
+            }
+
+            fn block_size(&self) -> usize {
+                Self::BLOCK_SIZE
+            }
+
+            fn digest_size(&self) -> usize {
+                Self::DIGEST_SIZE
+            }
+
+            impl_box_clone!();
+        }
+
+        impl $struct {
+            pub const BLOCK_SIZE: usize = $block_size;
+            pub const DIGEST_SIZE: usize = $digest_size;
+
+            pub fn new() -> Self {
+                Self {
+                    state: $krate::Digest::new(),
+                    is_done: false,
+                    digest: [0; $digest_size],
+                }
+            }
+        }
+
+        impl Default for $struct {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
@@ -1,6 +1,12 @@
-use std::mem::size_of;
+use core::mem::size_of;
 
-use crate::{paranoid_hash::Hasher, Error, Result};
+use crate::{
+    paranoid_hash::{
+        state::{StateReader, StateWriter},
+        Hasher,
+    },
+    Error, Result,
+};
 
 #[derive(Clone, Debug, Default)]
 pub struct CRC32 {
@@ -9,7 +15,7 @@ pub struct CRC32 {
     digest: [u8; 4],
 }
 
-#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CRC32C {
     state: u32,
     is_done: bool,
@@ -27,6 +33,11 @@ impl CRC32 {
             is_done: false,
         }
     }
+
+    /// Hashes `data` in one go, usable in const contexts. Uses the native implementation.
+    pub const fn hash_const(data: &[u8]) -> [u8; Self::DIGEST_SIZE] {
+        super::mine::CRC32::hash_const(data)
+    }
 }
 
 impl Hasher for CRC32 {
@@ -69,6 +80,20 @@ impl Hasher for CRC32 {
     fn digest_size(&self) -> usize {
         Self::DIGEST_SIZE
     }
+
+    fn save_state(&self) -> Result<Vec<u8>> {
+        let crc = self.state.clone().finalize();
+        Ok(save_state("CRC32", crc, self.is_done, self.digest))
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> Result<()> {
+        let crc;
+        (crc, self.is_done, self.digest) = restore_state("CRC32", state)?;
+        self.state = crc32fast::Hasher::new_with_initial(crc);
+        Ok(())
+    }
+
+    impl_box_clone!();
 }
 
 impl CRC32C {
@@ -82,6 +107,17 @@ impl CRC32C {
             is_done: false,
         }
     }
+
+    /// Hashes `data` in one go, usable in const contexts. Uses the native implementation.
+    pub const fn hash_const(data: &[u8]) -> [u8; Self::DIGEST_SIZE] {
+        super::mine::CRC32C::hash_const(data)
+    }
+}
+
+impl Default for CRC32C {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Hasher for CRC32C {
@@ -124,4 +160,36 @@ impl Hasher for CRC32C {
     fn digest_size(&self) -> usize {
         Self::DIGEST_SIZE
     }
+
+    fn save_state(&self) -> Result<Vec<u8>> {
+        Ok(save_state("CRC32C", self.state, self.is_done, self.digest))
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> Result<()> {
+        (self.state, self.is_done, self.digest) = restore_state("CRC32C", state)?;
+        Ok(())
+    }
+
+    impl_box_clone!();
+}
+
+// Saved states use the register of the table driven implementation, which is inverted before
+// the data ends, so they can be restored by both implementations.
+fn save_state(name: &str, crc: u32, is_done: bool, digest: [u8; 4]) -> Vec<u8> {
+    let mut writer = StateWriter::new(name);
+    writer.write(&if is_done { crc } else { !crc });
+    writer.write(&is_done);
+    writer.write(&digest);
+    writer.finish()
+}
+
+fn restore_state(name: &str, state: &[u8]) -> Result<(u32, bool, [u8; 4])> {
+    let mut reader = StateReader::new(state, name)?;
+    let register: u32 = reader.read()?;
+    let is_done = reader.read()?;
+    let digest = reader.read()?;
+    reader.finish()?;
+
+    let crc = if is_done { register } else { !register };
+    Ok((crc, is_done, digest))
 }
@@ -1,15 +1,15 @@
-use std::mem::size_of;
+use core::mem::size_of;
 
 use crate::{paranoid_hash::Hasher, Error, Result};
 
-#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CRC32 {
     state: u32,
     is_done: bool,
     digest: [u8; 4],
 }
 
-#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CRC32C {
     state: u32,
     is_done: bool,
@@ -27,6 +27,17 @@ impl CRC32 {
             is_done: false,
         }
     }
+
+    /// Hashes `data` in one go, usable in const contexts.
+    pub const fn hash_const(data: &[u8]) -> [u8; Self::DIGEST_SIZE] {
+        (!update_crc(&CRC32_TABLE, !0, data)).to_be_bytes()
+    }
+}
+
+impl Default for CRC32 {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Hasher for CRC32 {
@@ -35,10 +46,7 @@ impl Hasher for CRC32 {
             return Err(Error::UpdatingAfterFinished);
         }
 
-        for c in data {
-            self.state =
-                CRC32_TABLE[(self.state & 0xFF ^ *c as u32) as usize] ^ self.state.wrapping_shr(8);
-        }
+        self.state = update_crc(&CRC32_TABLE, self.state, data);
 
         Ok(())
     }
@@ -74,6 +82,10 @@ impl Hasher for CRC32 {
     fn digest_size(&self) -> usize {
         Self::DIGEST_SIZE
     }
+
+    impl_state!("CRC32", state, is_done, digest);
+
+    impl_box_clone!();
 }
 
 impl CRC32C {
@@ -87,6 +99,17 @@ impl CRC32C {
             is_done: false,
         }
     }
+
+    /// Hashes `data` in one go, usable in const contexts.
+    pub const fn hash_const(data: &[u8]) -> [u8; Self::DIGEST_SIZE] {
+        (!update_crc(&CRC32C_TABLE, !0, data)).to_be_bytes()
+    }
+}
+
+impl Default for CRC32C {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Hasher for CRC32C {
@@ -95,10 +118,7 @@ impl Hasher for CRC32C {
             return Err(Error::UpdatingAfterFinished);
         }
 
-        for c in data {
-            self.state =
-                CRC32C_TABLE[(self.state & 0xFF ^ *c as u32) as usize] ^ self.state.wrapping_shr(8);
-        }
+        self.state = update_crc(&CRC32C_TABLE, self.state, data);
 
         Ok(())
     }
@@ -134,6 +154,21 @@ impl Hasher for CRC32C {
     fn digest_size(&self) -> usize {
         Self::DIGEST_SIZE
     }
+
+    impl_state!("CRC32C", state, is_done, digest);
+
+    impl_box_clone!();
+}
+
+#[inline]
+const fn update_crc(table: &[u32; 256], mut state: u32, data: &[u8]) -> u32 {
+    let mut i = 0;
+    while i < data.len() {
+        state = table[(state & 0xFF ^ data[i] as u32) as usize] ^ state.wrapping_shr(8);
+        i += 1;
+    }
+
+    state
 }
 
 const CRC32_TABLE: [u32; 256] = [
@@ -1,16 +1,18 @@
+#[cfg(feature = "alter-impl")]
+pub(crate) mod alter;
+pub(crate) mod mine;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "alter-impl")] {
-        mod alter;
         pub use alter::*;
     } else {
-        mod mine;
         pub use self::mine::*;
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::paranoid_hash::tester::{HasherTestWrapper, TestData};
+    use crate::paranoid_hash::tester::{run_const_tests, HasherTestWrapper, TestData};
 
     use super::CRC32;
     use super::CRC32C;
@@ -33,4 +35,14 @@ mod tests {
 
         HasherTestWrapper::new(CRC32C::new()).run_tests(CRC32C_TESTS);
     }
+
+    #[test]
+    fn const_tests() {
+        const DIGEST: [u8; 4] = CRC32::hash_const(b"123456789");
+        assert_eq!(hex::encode(DIGEST), "cbf43926");
+
+        run_const_tests(CRC32::new(), CRC32::hash_const);
+
+        run_const_tests(CRC32C::new(), CRC32C::hash_const);
+    }
 }
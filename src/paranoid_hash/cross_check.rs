@@ -0,0 +1,221 @@
+use alloc::{boxed::Box, string::String};
+
+use crate::{
+    paranoid_hash::{Digest, Hasher},
+    Error, Result,
+};
+
+/// Feeds the same data to two implementations of an algorithm, and fails with
+/// `Error::ImplementationMismatch` when their digests differ. After a mismatch `digest` keeps
+/// failing until `reset`, so the digest can't be used unchecked.
+///
+/// Used by `Backend::CrossCheck`, any two hashers with the same block size and digest size can be
+/// paired though.
+#[derive(Debug)]
+pub struct CrossCheckHasher {
+    first: Box<dyn Hasher>,
+    second: Box<dyn Hasher>,
+    // hex digests of the first and the second implementation
+    mismatch: Option<(String, String)>,
+}
+
+impl CrossCheckHasher {
+    /// Fails with `Error::HasherSizeMismatch` if the block sizes or the digest sizes of the hashers
+    /// are different.
+    pub fn new(first: Box<dyn Hasher>, second: Box<dyn Hasher>) -> Result<Self> {
+        let block_sizes = (first.block_size(), second.block_size());
+        let digest_sizes = (first.digest_size(), second.digest_size());
+        if block_sizes.0 != block_sizes.1 || digest_sizes.0 != digest_sizes.1 {
+            return Err(Error::HasherSizeMismatch {
+                block_sizes,
+                digest_sizes,
+            });
+        }
+
+        Ok(Self {
+            first,
+            second,
+            mismatch: None,
+        })
+    }
+
+    pub fn into_inner(self) -> (Box<dyn Hasher>, Box<dyn Hasher>) {
+        (self.first, self.second)
+    }
+}
+
+impl Hasher for CrossCheckHasher {
+    fn update(&mut self, data: &[u8]) -> Result<()> {
+        self.first.update(data)?;
+        self.second.update(data)
+    }
+
+    fn update_last(&mut self, data: &[u8]) -> Result<()> {
+        self.first.update_last(data)?;
+        self.second.update_last(data)?;
+
+        let first = self.first.digest()?;
+        let second = self.second.digest()?;
+        if first != second {
+            self.mismatch = Some((Digest::new(first)?.to_hex(), Digest::new(second)?.to_hex()));
+        }
+
+        self.digest().map(|_| ())
+    }
+
+    fn digest(&self) -> Result<&[u8]> {
+        match &self.mismatch {
+            Some((first, second)) => Err(Error::ImplementationMismatch {
+                first: first.clone(),
+                second: second.clone(),
+            }),
+            None => self.first.digest(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+        self.mismatch = None;
+    }
+
+    fn block_size(&self) -> usize {
+        self.first.block_size()
+    }
+
+    fn digest_size(&self) -> usize {
+        self.first.digest_size()
+    }
+
+    fn box_clone(&self) -> Result<Box<dyn Hasher>> {
+        Ok(Box::new(Self {
+            first: self.first.box_clone()?,
+            second: self.second.box_clone()?,
+            mismatch: self.mismatch.clone(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    #[cfg(feature = "md5")]
+    use crate::paranoid_hash::MD5;
+    #[cfg(feature = "crc32")]
+    use crate::paranoid_hash::{BufferedHasher, CRC32, CRC32C};
+    use crate::{
+        paranoid_hash::{Backend, Hasher, HasherTag},
+        Error,
+    };
+
+    use super::CrossCheckHasher;
+
+    #[cfg(all(feature = "crc32", feature = "md5"))]
+    #[test]
+    fn cross_check_tests() {
+        let mut hasher = CrossCheckHasher::new(
+            HasherTag::MD5.new_hasher(),
+            HasherTag::MD5.new_hasher_with(Backend::Native).unwrap(),
+        )
+        .unwrap();
+        hasher.update(&[0x61; 64]).unwrap();
+        assert_eq!(
+            hex::encode(hasher.peek_digest().unwrap()),
+            "014842d480b571495a4a0363793f7367"
+        );
+        hasher.update_last(&[]).unwrap();
+        assert_eq!(
+            hex::encode(hasher.digest().unwrap()),
+            "014842d480b571495a4a0363793f7367"
+        );
+
+        hasher.reset();
+        assert!(matches!(hasher.digest(), Err(Error::NotFinished)));
+
+        // same sizes, different algorithms
+        let mut hasher =
+            CrossCheckHasher::new(Box::new(CRC32::new()), Box::new(CRC32C::new())).unwrap();
+        hasher.update("123456789".as_bytes()).unwrap();
+        match hasher.update_last(&[]) {
+            Err(Error::ImplementationMismatch { first, second }) => {
+                assert_eq!(first, "cbf43926");
+                assert_eq!(second, "e3069283");
+            }
+            result => panic!("{result:?}"),
+        }
+
+        // the digest stays unavailable after a mismatch
+        assert!(matches!(
+            hasher.digest(),
+            Err(Error::ImplementationMismatch { .. })
+        ));
+        assert!(matches!(
+            hasher.peek_digest(),
+            Err(Error::ImplementationMismatch { .. })
+        ));
+        assert!(matches!(
+            hasher.verify(&hex::decode("cbf43926").unwrap()),
+            Err(Error::ImplementationMismatch { .. })
+        ));
+        assert!(hasher.box_clone().unwrap().digest().is_err());
+
+        // an unfinished hasher is checked when peeking
+        hasher.reset();
+        hasher.update("123456789".as_bytes()).unwrap();
+        assert!(matches!(
+            hasher.peek_digest(),
+            Err(Error::ImplementationMismatch { .. })
+        ));
+
+        hasher.reset();
+        assert!(matches!(hasher.digest(), Err(Error::NotFinished)));
+    }
+
+    #[cfg(feature = "crc32")]
+    #[test]
+    fn buffered_mismatch_test() {
+        let hasher =
+            CrossCheckHasher::new(Box::new(CRC32::new()), Box::new(CRC32C::new())).unwrap();
+        let mut hasher = BufferedHasher::new(hasher);
+        hasher.update("123456789".as_bytes()).unwrap();
+        assert!(hasher.finalize().is_err());
+        assert!(matches!(
+            hasher.digest(),
+            Err(Error::ImplementationMismatch { .. })
+        ));
+    }
+
+    #[cfg(all(feature = "crc32", feature = "md5"))]
+    #[test]
+    fn size_mismatch_test() {
+        assert!(matches!(
+            CrossCheckHasher::new(Box::new(CRC32::new()), Box::new(MD5::new())),
+            Err(Error::HasherSizeMismatch {
+                block_sizes: (1, 64),
+                digest_sizes: (4, 16),
+            })
+        ));
+    }
+
+    #[test]
+    fn backend_tests() {
+        for tag in HasherTag::all().iter().copied() {
+            match tag.new_hasher_with(Backend::CrossCheck) {
+                Ok(mut hasher) => {
+                    assert!(tag.new_hasher_with(Backend::RustCrypto).is_ok());
+                    hasher.update_last("abc".as_bytes()).unwrap();
+
+                    let mut native = tag.new_hasher_with(Backend::Native).unwrap();
+                    native.update_last("abc".as_bytes()).unwrap();
+                    assert_eq!(hasher.digest().unwrap(), native.digest().unwrap());
+                }
+                Err(Error::BackendUnavailable(t, Backend::CrossCheck)) => {
+                    assert_eq!(t, tag);
+                    assert!(tag.new_hasher_with(Backend::RustCrypto).is_err());
+                }
+                Err(e) => panic!("{e}"),
+            }
+        }
+    }
+}
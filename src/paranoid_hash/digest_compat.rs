@@ -0,0 +1,350 @@
+// Nothing below but DigestHasher is used if no algorithm family is enabled
+#![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+use digest::{
+    typenum::Unsigned, Digest, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser,
+    Reset, Update,
+};
+
+use crate::{
+    paranoid_hash::{BufferedHasher, Hasher},
+    Error, Result,
+};
+
+// The digest traits take input of any length, so they are implemented for the buffered hashers.
+// Hashers are given by their name in paranoid_hash and sizes by their name in digest::consts.
+macro_rules! impl_digest {
+    ( $struct:ident, $block_size:ident, $digest_size:ident, "crypto" ) => {
+        impl_digest!($struct, $block_size, $digest_size);
+
+        impl HashMarker for BufferedHasher<crate::paranoid_hash::$struct> {}
+    };
+    ( $struct:ident, $block_size:ident, $digest_size:ident ) => {
+        impl_digest!(
+            @impl crate::paranoid_hash::$struct,
+            digest::consts::$block_size,
+            digest::consts::$digest_size
+        );
+    };
+    ( @impl $struct:ty, $block_size:ty, $digest_size:ty ) => {
+        const _: () = assert!(<$block_size>::USIZE == <$struct>::BLOCK_SIZE);
+        const _: () = assert!(<$digest_size>::USIZE == <$struct>::DIGEST_SIZE);
+
+        impl digest::core_api::BlockSizeUser for BufferedHasher<$struct> {
+            type BlockSize = $block_size;
+        }
+
+        impl OutputSizeUser for BufferedHasher<$struct> {
+            type OutputSize = $digest_size;
+        }
+
+        impl Update for BufferedHasher<$struct> {
+            fn update(&mut self, data: &[u8]) {
+                BufferedHasher::update(self, data).expect("failed to update hasher");
+            }
+        }
+
+        impl FixedOutput for BufferedHasher<$struct> {
+            fn finalize_into(mut self, out: &mut Output<Self>) {
+                finalize_into(&mut self, out);
+            }
+        }
+
+        impl FixedOutputReset for BufferedHasher<$struct> {
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                finalize_into(self, out);
+                BufferedHasher::reset(self);
+            }
+        }
+
+        impl Reset for BufferedHasher<$struct> {
+            fn reset(&mut self) {
+                BufferedHasher::reset(self);
+            }
+        }
+    };
+}
+
+// The digest traits can't fail, so errors panic. That includes the collisions found by SHA1DC,
+// a digest of colliding data must not be handed out as if nothing happened.
+fn finalize_into<H: Hasher>(hasher: &mut BufferedHasher<H>, out: &mut [u8]) {
+    match hasher.finalize() {
+        Ok(digest) => out.copy_from_slice(digest),
+        Err(e) => panic!("failed to finalize hasher: {e}"),
+    }
+}
+
+#[cfg(feature = "crc32")]
+impl_digest!(CRC32, U1, U4);
+#[cfg(feature = "crc32")]
+impl_digest!(CRC32C, U1, U4);
+#[cfg(feature = "md2")]
+impl_digest!(MD2, U16, U16, "crypto");
+#[cfg(feature = "md4")]
+impl_digest!(MD4, U64, U16, "crypto");
+#[cfg(feature = "md5")]
+impl_digest!(MD5, U64, U16, "crypto");
+#[cfg(feature = "sha1")]
+impl_digest!(SHA1, U64, U20, "crypto");
+#[cfg(feature = "sha1dc")]
+impl_digest!(SHA1DC, U64, U20, "crypto");
+#[cfg(feature = "sha2")]
+impl_digest!(SHA2_224, U64, U28, "crypto");
+#[cfg(feature = "sha2")]
+impl_digest!(SHA2_256, U64, U32, "crypto");
+#[cfg(feature = "sha2")]
+impl_digest!(SHA2_384, U128, U48, "crypto");
+#[cfg(feature = "sha2")]
+impl_digest!(SHA2_512, U128, U64, "crypto");
+#[cfg(feature = "sha3")]
+impl_digest!(SHA3_224, U144, U28, "crypto");
+#[cfg(feature = "sha3")]
+impl_digest!(SHA3_256, U136, U32, "crypto");
+#[cfg(feature = "sha3")]
+impl_digest!(SHA3_384, U104, U48, "crypto");
+#[cfg(feature = "sha3")]
+impl_digest!(SHA3_512, U72, U64, "crypto");
+#[cfg(feature = "murmur3")]
+impl_digest!(MURMUR3_X86_32, U4, U4);
+#[cfg(feature = "murmur3")]
+impl_digest!(MURMUR3_X64_128, U16, U16);
+#[cfg(feature = "fnv")]
+impl_digest!(FNV1_32, U1, U4);
+#[cfg(feature = "fnv")]
+impl_digest!(FNV1A_32, U1, U4);
+#[cfg(feature = "fnv")]
+impl_digest!(FNV1_64, U1, U8);
+#[cfg(feature = "fnv")]
+impl_digest!(FNV1A_64, U1, U8);
+#[cfg(feature = "fnv")]
+impl_digest!(FNV1_128, U1, U16);
+#[cfg(feature = "fnv")]
+impl_digest!(FNV1A_128, U1, U16);
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_128_3, U128, U16, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_160_3, U128, U20, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_192_3, U128, U24, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_224_3, U128, U28, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_256_3, U128, U32, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_128_4, U128, U16, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_160_4, U128, U20, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_192_4, U128, U24, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_224_4, U128, U28, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_256_4, U128, U32, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_128_5, U128, U16, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_160_5, U128, U20, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_192_5, U128, U24, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_224_5, U128, U28, "crypto");
+#[cfg(feature = "haval")]
+impl_digest!(HAVAL_256_5, U128, U32, "crypto");
+#[cfg(feature = "snefru")]
+impl_digest!(SNEFRU_128, U48, U16, "crypto");
+#[cfg(feature = "snefru")]
+impl_digest!(SNEFRU_256, U32, U32, "crypto");
+#[cfg(feature = "has160")]
+impl_digest!(HAS160, U64, U20, "crypto");
+#[cfg(feature = "edonr")]
+impl_digest!(EDONR_256, U64, U32, "crypto");
+#[cfg(feature = "edonr")]
+impl_digest!(EDONR_512, U128, U64, "crypto");
+
+/// Drives a hasher from the digest crate, e.g. one from RustCrypto, through the `Hasher` trait.
+#[derive(Clone, Debug, Default)]
+pub struct DigestHasher<D: Digest> {
+    state: D,
+    is_done: bool,
+    digest: Output<D>,
+}
+
+impl<D: Digest> DigestHasher<D> {
+    pub fn new(state: D) -> Self {
+        Self {
+            state,
+            is_done: false,
+            digest: Default::default(),
+        }
+    }
+}
+
+impl<D: Digest> Hasher for DigestHasher<D> {
+    fn update(&mut self, data: &[u8]) -> Result<()> {
+        if self.is_done {
+            return Err(Error::UpdatingAfterFinished);
+        }
+
+        Digest::update(&mut self.state, data);
+        Ok(())
+    }
+
+    fn update_last(&mut self, data: &[u8]) -> Result<()> {
+        if self.is_done {
+            return Err(Error::UpdatingAfterFinished);
+        }
+
+        self.is_done = true;
+
+        Digest::update(&mut self.state, data);
+        self.digest = core::mem::replace(&mut self.state, D::new()).finalize();
+        Ok(())
+    }
+
+    fn digest(&self) -> Result<&[u8]> {
+        if !self.is_done {
+            return Err(Error::NotFinished);
+        }
+
+        Ok(&self.digest)
+    }
+
+    fn reset(&mut self) {
+        self.state = D::new();
+        self.is_done = false;
+    }
+
+    // digest hashers accept data of any length
+    fn block_size(&self) -> usize {
+        1
+    }
+
+    fn digest_size(&self) -> usize {
+        <D as Digest>::output_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    use digest::Digest;
+    use hmac::{Mac, SimpleHmac};
+
+    #[cfg(feature = "crc32")]
+    use crate::paranoid_hash::CRC32;
+    #[cfg(feature = "md5")]
+    use crate::paranoid_hash::MD5;
+    #[cfg(feature = "sha1")]
+    use crate::paranoid_hash::SHA1;
+    #[cfg(feature = "sha2")]
+    use crate::paranoid_hash::SHA2_256;
+    #[cfg(feature = "sha3")]
+    use crate::paranoid_hash::SHA3_256;
+    #[cfg(feature = "sha1dc")]
+    use crate::paranoid_hash::{tester::SHATTERED_1, SHA1DC};
+    use crate::{
+        paranoid_hash::{BufferedHasher, Hasher},
+        Error,
+    };
+
+    use super::DigestHasher;
+
+    const DATA: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+
+    fn hash<D: Digest>() -> String {
+        let mut hasher = D::new();
+        for chunk in DATA.chunks(5) {
+            Digest::update(&mut hasher, chunk);
+        }
+        hex::encode(hasher.finalize())
+    }
+
+    #[test]
+    fn digest_tests() {
+        #[cfg(feature = "md5")]
+        assert_eq!(
+            hash::<BufferedHasher<MD5>>(),
+            "9e107d9d372bb6826bd81d3542a419d6"
+        );
+        #[cfg(feature = "sha1")]
+        assert_eq!(
+            hash::<BufferedHasher<SHA1>>(),
+            "2fd4e1c67a2d28fced849ee1bb76e7391b93eb12"
+        );
+        #[cfg(feature = "sha1dc")]
+        assert_eq!(
+            hash::<BufferedHasher<SHA1DC>>(),
+            "2fd4e1c67a2d28fced849ee1bb76e7391b93eb12"
+        );
+        #[cfg(feature = "sha2")]
+        assert_eq!(
+            hash::<BufferedHasher<SHA2_256>>(),
+            "d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592"
+        );
+        #[cfg(feature = "sha3")]
+        assert_eq!(
+            hash::<BufferedHasher<SHA3_256>>(),
+            "69070dda01975c8c120c3aada1b282394e7f032fa9cf32f4cb2259a0897dfc04"
+        );
+
+        #[cfg(feature = "crc32")]
+        {
+            let mut hasher = BufferedHasher::new(CRC32::new());
+            digest::Update::update(&mut hasher, DATA);
+            assert_eq!(
+                hex::encode(digest::FixedOutputReset::finalize_fixed_reset(&mut hasher)),
+                "414fa339"
+            );
+            digest::Update::update(&mut hasher, DATA);
+            assert_eq!(
+                hex::encode(digest::FixedOutput::finalize_fixed(hasher)),
+                "414fa339"
+            );
+        }
+    }
+
+    #[cfg(feature = "sha1dc")]
+    #[test]
+    #[should_panic(expected = "collision attack detected")]
+    fn collision_test() {
+        let mut hasher = <BufferedHasher<SHA1DC> as Digest>::new();
+        Digest::update(&mut hasher, hex::decode(SHATTERED_1).unwrap());
+        Digest::finalize(hasher);
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn hmac_test() {
+        // RFC 4231 test case 2
+        let mut mac = SimpleHmac::<BufferedHasher<SHA2_256>>::new_from_slice(b"Jefe").unwrap();
+        mac.update(b"what do ya want for nothing?");
+        assert_eq!(
+            hex::encode(mac.finalize().into_bytes()),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn digest_hasher_test() {
+        let mut hasher = DigestHasher::new(BufferedHasher::new(SHA2_256::new()));
+        hasher.update(&DATA[..7]).unwrap();
+        hasher.update_last(&DATA[7..]).unwrap();
+        assert_eq!(hasher.digest_size(), SHA2_256::DIGEST_SIZE);
+        assert_eq!(
+            hex::encode(hasher.digest().unwrap()),
+            "d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592"
+        );
+        assert!(hasher.update(DATA).is_err());
+        assert!(matches!(hasher.save_state(), Err(Error::StateUnsupported)));
+        assert!(matches!(hasher.box_clone(), Err(Error::CloneUnsupported)));
+
+        hasher.reset();
+        hasher.update_last(&[]).unwrap();
+        assert_eq!(
+            hex::encode(hasher.digest().unwrap()),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+}
@@ -0,0 +1,433 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, hash, ops::Deref, str::FromStr};
+
+use crate::{Error, Result};
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// An owned digest, stored inline.
+///
+/// `Display` and `FromStr` use lowercase hex, comparisons take the same time no matter where the
+/// digests differ.
+#[derive(Clone, Copy)]
+pub struct Digest {
+    bytes: [u8; Self::MAX_SIZE],
+    length: u8,
+}
+
+impl Digest {
+    /// Size of the largest digest produced by the built-in hashers.
+    pub const MAX_SIZE: usize = 64;
+
+    pub fn new(digest: &[u8]) -> Result<Self> {
+        if digest.len() > Self::MAX_SIZE {
+            return Err(Error::InvalidDigest("digest is longer than 64 bytes"));
+        }
+
+        let mut bytes = [0; Self::MAX_SIZE];
+        bytes[..digest.len()].copy_from_slice(digest);
+        Ok(Self {
+            bytes,
+            length: digest.len() as u8,
+        })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.length as usize]
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.to_string()
+    }
+
+    /// RFC 4648 base32 with padding.
+    pub fn to_base32(&self) -> String {
+        encode_bits(self.as_bytes(), 5, BASE32_ALPHABET, 8)
+    }
+
+    /// RFC 4648 base64 with padding.
+    pub fn to_base64(&self) -> String {
+        encode_bits(self.as_bytes(), 6, BASE64_ALPHABET, 4)
+    }
+
+    /// RFC 4648 URL and filename safe base64 without padding.
+    pub fn to_base64url(&self) -> String {
+        encode_bits(self.as_bytes(), 6, BASE64URL_ALPHABET, 1)
+    }
+
+    /// Base58 with the alphabet used by Bitcoin and IPFS.
+    pub fn to_base58(&self) -> String {
+        let bytes = self.as_bytes();
+        let zeros = bytes.iter().take_while(|b| **b == 0).count();
+
+        // little endian base58 digits
+        let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+        for byte in &bytes[zeros..] {
+            let mut carry = *byte as u32;
+            for digit in digits.iter_mut() {
+                carry += (*digit as u32) << 8;
+                *digit = (carry % 58) as u8;
+                carry /= 58;
+            }
+
+            while carry != 0 {
+                digits.push((carry % 58) as u8);
+                carry /= 58;
+            }
+        }
+
+        let mut result = "1".repeat(zeros);
+        result.extend(
+            digits
+                .iter()
+                .rev()
+                .map(|d| BASE58_ALPHABET[*d as usize] as char),
+        );
+        result
+    }
+}
+
+// Splits the data into groups of `bits` bits, the output is padded with '=' to a multiple of
+// `block` characters.
+fn encode_bits(data: &[u8], bits: u32, alphabet: &[u8], block: usize) -> String {
+    let mask = (1 << bits) - 1;
+    let mut result = String::with_capacity((data.len() * 8).div_ceil(bits as usize) + block);
+    let mut buffer = 0u32;
+    let mut buffered_bits = 0;
+
+    for byte in data {
+        buffer = buffer << 8 | *byte as u32;
+        buffered_bits += 8;
+        while buffered_bits >= bits {
+            buffered_bits -= bits;
+            result.push(alphabet[(buffer >> buffered_bits & mask) as usize] as char);
+        }
+    }
+
+    if buffered_bits != 0 {
+        result.push(alphabet[(buffer << (bits - buffered_bits) & mask) as usize] as char);
+    }
+
+    while !result.len().is_multiple_of(block) {
+        result.push('=');
+    }
+
+    result
+}
+
+/// Compares two slices in time which depends only on their lengths.
+pub(crate) fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+
+    let difference = left
+        .iter()
+        .zip(right)
+        .fold(0u8, |difference, (l, r)| difference | (l ^ r));
+    core::hint::black_box(difference) == 0
+}
+
+/// Compares a digest with the expected one in constant time, fails with `Error::DigestMismatch` if
+/// they differ.
+pub fn verify_digest(expected: &[u8], actual: &[u8]) -> Result<()> {
+    if constant_time_eq(expected, actual) {
+        return Ok(());
+    }
+
+    Err(Error::DigestMismatch {
+        expected: encode_hex(expected),
+        actual: encode_hex(actual),
+    })
+}
+
+fn encode_hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+impl Deref for Digest {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for Digest {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl TryFrom<&[u8]> for Digest {
+    type Error = Error;
+
+    fn try_from(value: &[u8]) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl PartialEq for Digest {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq(self.as_bytes(), other.as_bytes())
+    }
+}
+
+impl Eq for Digest {}
+
+impl PartialEq<[u8]> for Digest {
+    fn eq(&self, other: &[u8]) -> bool {
+        constant_time_eq(self.as_bytes(), other)
+    }
+}
+
+impl PartialEq<&[u8]> for Digest {
+    fn eq(&self, other: &&[u8]) -> bool {
+        constant_time_eq(self.as_bytes(), other)
+    }
+}
+
+impl hash::Hash for Digest {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
+impl fmt::Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.as_bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::UpperHex for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.as_bytes() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Digest({})", self)
+    }
+}
+
+impl FromStr for Digest {
+    type Err = Error;
+
+    /// Parses hex digests, both cases are accepted.
+    fn from_str(s: &str) -> Result<Self> {
+        fn value(c: u8) -> Result<u8> {
+            match c {
+                b'0'..=b'9' => Ok(c - b'0'),
+                b'a'..=b'f' => Ok(c - b'a' + 10),
+                b'A'..=b'F' => Ok(c - b'A' + 10),
+                _ => Err(Error::InvalidDigest("invalid hex character")),
+            }
+        }
+
+        let s = s.as_bytes();
+        if !s.len().is_multiple_of(2) {
+            return Err(Error::InvalidDigest("odd number of hex characters"));
+        }
+
+        if s.len() / 2 > Self::MAX_SIZE {
+            return Err(Error::InvalidDigest("digest is longer than 64 bytes"));
+        }
+
+        let mut bytes = [0; Self::MAX_SIZE];
+        for (byte, pair) in bytes.iter_mut().zip(s.chunks_exact(2)) {
+            *byte = value(pair[0])? << 4 | value(pair[1])?;
+        }
+
+        Ok(Self {
+            bytes,
+            length: (s.len() / 2) as u8,
+        })
+    }
+}
+
+// Human readable formats get hex strings, the others get bytes.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Digest {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Digest {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Digest;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    f,
+                    "a hex string or bytes of at most {} bytes",
+                    Digest::MAX_SIZE
+                )
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> core::result::Result<Digest, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> core::result::Result<Digest, E> {
+                Digest::new(v).map_err(E::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(Visitor)
+        } else {
+            deserializer.deserialize_bytes(Visitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    #[cfg(feature = "md5")]
+    use crate::paranoid_hash::{Hasher, MD5};
+    use crate::Error;
+
+    use super::{verify_digest, Digest};
+
+    const MD5_RESULT: &str = "9e107d9d372bb6826bd81d3542a419d6";
+
+    #[test]
+    fn hex_tests() {
+        let digest: Digest = MD5_RESULT.parse().unwrap();
+        assert_eq!(digest.len(), 16);
+        assert_eq!(digest.to_string(), MD5_RESULT);
+        assert_eq!(format!("{:X}", digest), MD5_RESULT.to_uppercase());
+        assert_eq!(format!("{:?}", digest), format!("Digest({MD5_RESULT})"));
+        assert_eq!(digest, MD5_RESULT.to_uppercase().parse::<Digest>().unwrap());
+        assert_eq!(digest, hex::decode(MD5_RESULT).unwrap().as_slice());
+
+        assert!("9e1".parse::<Digest>().is_err());
+        assert!("9g".parse::<Digest>().is_err());
+        assert!("00".repeat(Digest::MAX_SIZE + 1).parse::<Digest>().is_err());
+        assert!(Digest::new(&[0; Digest::MAX_SIZE + 1]).is_err());
+        assert_eq!("".parse::<Digest>().unwrap().to_string(), "");
+    }
+
+    #[test]
+    fn eq_tests() {
+        let digest = Digest::new(&[1, 2, 3]).unwrap();
+        assert_eq!(digest, Digest::new(&[1, 2, 3]).unwrap());
+        assert_ne!(digest, Digest::new(&[1, 2, 4]).unwrap());
+        assert_ne!(digest, Digest::new(&[1, 2]).unwrap());
+        assert_ne!(digest, Digest::new(&[1, 2, 3, 0]).unwrap());
+    }
+
+    #[cfg(feature = "md5")]
+    #[test]
+    fn verify_tests() {
+        let expected = hex::decode(MD5_RESULT).unwrap();
+        let data = "The quick brown fox jumps over the lazy dog".as_bytes();
+
+        let mut hasher = MD5::new();
+        hasher.update_last(data).unwrap();
+        hasher.verify(&expected).unwrap();
+        verify_digest(&expected, hasher.digest().unwrap()).unwrap();
+
+        // unfinished hashers are finalized on a copy
+        let mut hasher = MD5::new();
+        hasher.update(&[0; 64]).unwrap();
+        assert!(hasher.verify(&expected).is_err());
+        assert!(matches!(hasher.digest(), Err(Error::NotFinished)));
+
+        match verify_digest(&expected, &[0xab, 0x01]) {
+            Err(Error::DigestMismatch { expected, actual }) => {
+                assert_eq!(expected, MD5_RESULT);
+                assert_eq!(actual, "ab01");
+            }
+            result => panic!("{result:?}"),
+        }
+    }
+
+    #[test]
+    fn encoding_tests() {
+        // RFC 4648 test vectors
+        let vectors = [
+            ("", "", "", ""),
+            ("f", "MY======", "Zg==", "Zg"),
+            ("fo", "MZXQ====", "Zm8=", "Zm8"),
+            ("foo", "MZXW6===", "Zm9v", "Zm9v"),
+            ("foob", "MZXW6YQ=", "Zm9vYg==", "Zm9vYg"),
+            ("fooba", "MZXW6YTB", "Zm9vYmE=", "Zm9vYmE"),
+            ("foobar", "MZXW6YTBOI======", "Zm9vYmFy", "Zm9vYmFy"),
+        ];
+
+        for (data, base32, base64, base64url) in vectors {
+            let digest = Digest::new(data.as_bytes()).unwrap();
+            assert_eq!(digest.to_base32(), base32);
+            assert_eq!(digest.to_base64(), base64);
+            assert_eq!(digest.to_base64url(), base64url);
+        }
+
+        let digest = Digest::new(&[0xfb, 0xff, 0xbf]).unwrap();
+        assert_eq!(digest.to_base64(), "+/+/");
+        assert_eq!(digest.to_base64url(), "-_-_");
+
+        assert_eq!(
+            Digest::new(b"Hello World!").unwrap().to_base58(),
+            "2NEpo7TZRRrLZSi2U"
+        );
+        assert_eq!(
+            Digest::new(&[0, 0, 0x28, 0x7f, 0xb4, 0xcd])
+                .unwrap()
+                .to_base58(),
+            "11233QC4"
+        );
+        assert_eq!(Digest::new(&[0]).unwrap().to_base58(), "1");
+        assert_eq!(Digest::new(&[]).unwrap().to_base58(), "");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_tests() {
+        let digest: Digest = MD5_RESULT.parse().unwrap();
+        let json = serde_json::to_string(&digest).unwrap();
+        assert_eq!(json, format!("\"{MD5_RESULT}\""));
+        assert_eq!(serde_json::from_str::<Digest>(&json).unwrap(), digest);
+        assert!(serde_json::from_str::<Digest>("\"xyz\"").is_err());
+    }
+}
@@ -0,0 +1,370 @@
+use core::mem::size_of;
+
+use crate::{paranoid_hash::Hasher, Error, Result};
+
+macro_rules! create_quasigroup {
+    ( $name:ident, $word:ty, $c1:expr, $c2:expr, $r1:expr, $r2:expr ) => {
+        #[inline(always)]
+        fn $name(x: [$word; 8], y: [$word; 8]) -> [$word; 8] {
+            // the first latin square
+            let t8 = x[0].wrapping_add(x[4]);
+            let t9 = x[1].wrapping_add(x[7]);
+            let t12 = t8.wrapping_add(t9);
+            let t10 = x[2].wrapping_add(x[3]);
+            let t11 = x[5].wrapping_add(x[6]);
+            let t13 = t10.wrapping_add(t11);
+            let t0 = $c1.wrapping_add(t12).wrapping_add(x[2]);
+            let t1 = t12.wrapping_add(x[3]).rotate_left($r1[0]);
+            let t2 = t12.wrapping_add(x[6]).rotate_left($r1[1]);
+            let t3 = t13.wrapping_add(x[7]).rotate_left($r1[2]);
+            let t4 = x[1].wrapping_add(t13).rotate_left($r1[3]);
+            let t5 = t8.wrapping_add(t10).wrapping_add(x[5]).rotate_left($r1[4]);
+            let t6 = x[0].wrapping_add(t9).wrapping_add(t11).rotate_left($r1[5]);
+            let t7 = t13.wrapping_add(x[4]).rotate_left($r1[6]);
+
+            let t16 = t0 ^ t4;
+            let t17 = t1 ^ t7;
+            let t18 = t2 ^ t3;
+            let t19 = t5 ^ t6;
+            let t8 = t3 ^ t19;
+            let t9 = t2 ^ t19;
+            let t10 = t18 ^ t5;
+            let t11 = t16 ^ t1;
+            let t12 = t16 ^ t7;
+            let t13 = t17 ^ t6;
+            let t14 = t18 ^ t4;
+            let t15 = t0 ^ t17;
+
+            // the second orthogonal latin square
+            let t16 = y[0].wrapping_add(y[1]);
+            let t17 = y[2].wrapping_add(y[5]);
+            let t20 = t16.wrapping_add(t17);
+            let t18 = y[3].wrapping_add(y[4]);
+            let t22 = t16.wrapping_add(t18);
+            let t19 = y[6].wrapping_add(y[7]);
+            let t21 = t18.wrapping_add(t19);
+            let t23 = t17.wrapping_add(t19);
+            let t0 = $c2.wrapping_add(t20).wrapping_add(y[7]);
+            let t1 = t22.wrapping_add(y[6]).rotate_left($r2[0]);
+            let t2 = t20.wrapping_add(y[3]).rotate_left($r2[1]);
+            let t3 = y[2].wrapping_add(t21).rotate_left($r2[2]);
+            let t4 = t22.wrapping_add(y[5]).rotate_left($r2[3]);
+            let t5 = t23.wrapping_add(y[4]).rotate_left($r2[4]);
+            let t6 = y[1].wrapping_add(t23).rotate_left($r2[5]);
+            let t7 = y[0].wrapping_add(t21).rotate_left($r2[6]);
+
+            let t16 = t0 ^ t1;
+            let t17 = t2 ^ t5;
+            let t18 = t3 ^ t4;
+            let t19 = t6 ^ t7;
+
+            [
+                t11.wrapping_add(t16 ^ t5),
+                t12.wrapping_add(t2 ^ t19),
+                t13.wrapping_add(t16 ^ t3),
+                t14.wrapping_add(t0 ^ t18),
+                t15.wrapping_add(t1 ^ t17),
+                t8.wrapping_add(t18 ^ t6),
+                t9.wrapping_add(t17 ^ t7),
+                t10.wrapping_add(t4 ^ t19),
+            ]
+        }
+    };
+}
+
+macro_rules! create_edonr {
+    ( $struct:ident, $word:ty, $block_size:expr, $digest_size:expr, $quasigroup:ident, $init:expr ) => {
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $struct {
+            state: [$word; 16],
+            count: u64,
+            is_done: bool,
+            digest: [u8; Self::DIGEST_SIZE],
+        }
+
+        impl Hasher for $struct {
+            fn update(&mut self, data: &[u8]) -> Result<()> {
+                transmute_update!(self, data, Self::BLOCK_SIZE, $word, u64, "wrapping", "le");
+            }
+
+            fn update_last(&mut self, data: &[u8]) -> Result<()> {
+                if self.is_done {
+                    return Err(Error::UpdatingAfterFinished);
+                }
+
+                if data.len() > Self::BLOCK_SIZE {
+                    return Err(Error::DataTooLarge(data.len(), Self::BLOCK_SIZE));
+                }
+
+                self.count = self.count.wrapping_add((data.len() as u64).wrapping_mul(8));
+
+                let mut final_block = [0u8; Self::BLOCK_SIZE * 2];
+                final_block[..data.len()].copy_from_slice(data);
+                final_block[data.len()] = 0x80;
+
+                let count = self.count.to_le_bytes();
+
+                let final_block_slice = if data.len() + count.len() < Self::BLOCK_SIZE {
+                    &mut final_block[..Self::BLOCK_SIZE]
+                } else {
+                    &mut final_block[..]
+                };
+                let length = final_block_slice.len();
+                final_block_slice[length - count.len()..].copy_from_slice(&count);
+
+                self.update(final_block_slice)?;
+
+                for (d, s) in self
+                    .digest
+                    .chunks_exact_mut(size_of::<$word>())
+                    .zip(self.state[8..].iter())
+                {
+                    d.copy_from_slice(&s.to_le_bytes());
+                }
+
+                self.is_done = true;
+
+                Ok(())
+            }
+
+            fn digest(&self) -> Result<&[u8]> {
+                if !self.is_done {
+                    return Err(Error::NotFinished);
+                }
+
+                Ok(&self.digest)
+            }
+
+            fn reset(&mut self) {
+                *self = Self::new();
+            }
+
+            fn block_size(&self) -> usize {
+                Self::BLOCK_SIZE
+            }
+
+            fn digest_size(&self) -> usize {
+                Self::DIGEST_SIZE
+            }
+
+            impl_state!(stringify!($struct), state, count, is_done, digest);
+
+            impl_box_clone!();
+        }
+
+        impl $struct {
+            pub const BLOCK_SIZE: usize = $block_size;
+            pub const DIGEST_SIZE: usize = $digest_size;
+
+            pub const fn new() -> Self {
+                Self {
+                    state: $init,
+                    count: 0,
+                    is_done: false,
+                    digest: [0; Self::DIGEST_SIZE],
+                }
+            }
+
+            #[inline]
+            fn update_block(&mut self, block: &[$word; 16]) {
+                let [low, high] = split(block);
+                let [state_low, state_high] = split(&self.state);
+
+                let mut low_reversed = low;
+                low_reversed.reverse();
+                let mut high_reversed = high;
+                high_reversed.reverse();
+
+                // first row of quasigroup e-transformations
+                let p1 = $quasigroup(high_reversed, low);
+                let p2 = $quasigroup(p1, high);
+
+                // second row
+                let p1 = $quasigroup(state_high, p1);
+                let p2 = $quasigroup(p1, p2);
+
+                // third row
+                let p1 = $quasigroup(p1, state_low);
+                let p2 = $quasigroup(p2, p1);
+
+                // fourth row
+                let state_low = $quasigroup(low_reversed, p1);
+                let state_high = $quasigroup(state_low, p2);
+
+                self.state[..8].copy_from_slice(&state_low);
+                self.state[8..].copy_from_slice(&state_high);
+            }
+        }
+
+        impl Default for $struct {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+create_quasigroup!(
+    quasigroup_256,
+    u32,
+    0xaaaaaaaau32,
+    0x55555555u32,
+    [5, 11, 13, 17, 19, 29, 31],
+    [3, 7, 11, 17, 19, 23, 29]
+);
+create_quasigroup!(
+    quasigroup_512,
+    u64,
+    0xaaaaaaaaaaaaaaaau64,
+    0x5555555555555555u64,
+    [5, 19, 29, 31, 41, 57, 61],
+    [3, 17, 23, 31, 37, 45, 59]
+);
+
+create_edonr!(
+    EDONR_256,
+    u32,
+    64,
+    32,
+    quasigroup_256,
+    [
+        0x40414243, 0x44454647, 0x48494a4b, 0x4c4d4e4f, 0x50515253, 0x54555657, 0x58595a5b,
+        0x5c5d5e5f, 0x60616263, 0x64656667, 0x68696a6b, 0x6c6d6e6f, 0x70717273, 0x74757677,
+        0x78797a7b, 0x7c7d7e7f,
+    ]
+);
+create_edonr!(
+    EDONR_512,
+    u64,
+    128,
+    64,
+    quasigroup_512,
+    [
+        0x8081828384858687,
+        0x88898a8b8c8d8e8f,
+        0x9091929394959697,
+        0x98999a9b9c9d9e9f,
+        0xa0a1a2a3a4a5a6a7,
+        0xa8a9aaabacadaeaf,
+        0xb0b1b2b3b4b5b6b7,
+        0xb8b9babbbcbdbebf,
+        0xc0c1c2c3c4c5c6c7,
+        0xc8c9cacbcccdcecf,
+        0xd0d1d2d3d4d5d6d7,
+        0xd8d9dadbdcdddedf,
+        0xe0e1e2e3e4e5e6e7,
+        0xe8e9eaebecedeeef,
+        0xf0f1f2f3f4f5f6f7,
+        0xf8f9fafbfcfdfeff,
+    ]
+);
+
+#[inline(always)]
+fn split<T: Copy + Default>(words: &[T; 16]) -> [[T; 8]; 2] {
+    let mut low = [T::default(); 8];
+    let mut high = [T::default(); 8];
+    low.copy_from_slice(&words[..8]);
+    high.copy_from_slice(&words[8..]);
+    [low, high]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::paranoid_hash::{
+        tester::{HasherTestWrapper, TestData},
+        Hasher,
+    };
+
+    use super::{EDONR_256, EDONR_512};
+
+    const EDONR_256_TESTS: &[TestData] = &[
+        TestData {
+            data: "".as_bytes(),
+            repeat: 1,
+            result: "86e7c84024c55dbdc9339b395c95e88db8f781719851ad1d237c6e6a8e370b80",
+        },
+        TestData {
+            data: "a".as_bytes(),
+            repeat: 1,
+            result: "943aa9225a2cf154ec2e4dd81237720ba538ca8df2fd83c0b893c5d265f353a0",
+        },
+        TestData {
+            data: "abc".as_bytes(),
+            repeat: 1,
+            result: "0360f65d97c2152ea6ebe3d462bf49831e2d5f67b6140992320585d89fd271ce",
+        },
+        TestData {
+            data: "message digest".as_bytes(),
+            repeat: 1,
+            result: "8d27558f4dd9307614a8166cadb136927d1e79a0c04bd8ef77c3fafc0917e28a",
+        },
+        TestData {
+            data: "The quick brown fox jumps over the lazy dog".as_bytes(),
+            repeat: 1,
+            result: "e77a5ac00923b86c1811d42f1cb1198f43412a6d987dc98bdae11e6d91399609",
+        },
+        TestData {
+            data: "a".as_bytes(),
+            repeat: 1000000,
+            result: "56f4b8dc0a41c8ea0a6a42c949883cd5dc25df8cf4e43ad474fd4492a7a07966",
+        },
+    ];
+
+    const EDONR_512_TESTS: &[TestData] = &[
+        TestData {
+            data: "".as_bytes(),
+            repeat: 1,
+            result: "c7afbdf3e5b4590eb0b25000bf83fb16d4f9b722ee7f9a2dc2bd382035e8ee38d6f6f15c7b8eec85355ac59af989799950c64557eab0e687d0fcbdba90ae9704",
+        },
+        TestData {
+            data: "a".as_bytes(),
+            repeat: 1,
+            result: "b59ec44f7beef8a04ceed38a973d77c65e22e9458d5f67b497948da34986c093b5efc5483fbee55f2f740fcad31f18d80db44bb6b8843e7fd599188e7c07233b",
+        },
+        TestData {
+            data: "abc".as_bytes(),
+            repeat: 1,
+            result: "fe79bcfa310245d9139da8bc91b99fd022326f7f3aca1dfdfb6c84e4125d71fe9bb6a1d41afce358f8472835220a7829d5146b2bbfc8e5c2627f60a9b517c1a4",
+        },
+        TestData {
+            data: "message digest".as_bytes(),
+            repeat: 1,
+            result: "a76b6c5ca8778f39ec1f85d64badbdbf329725c9a6fb92656d94a82922a26fd51d271a6f135f33157143b960cd8d7d20dc99503aa39871fd64050e061689e4e3",
+        },
+        TestData {
+            data: "The quick brown fox jumps over the lazy dog".as_bytes(),
+            repeat: 1,
+            result: "b986adabfa9adb1e5b152b6d64c733389082e354fde2fd9740faea6766f440ea4391fc745bb9b11a821756944077bb30723f616645492c70fa4c614db7e9d45b",
+        },
+        TestData {
+            data: "a".as_bytes(),
+            repeat: 1000000,
+            result: "b4a5a255d67869c990fe79b5fcbda69958794b8003f01fd11e90fefec35f22bd84ffa2e248e8b3c1acd9b7efac5bc66616e234a6e938d3526dee26bd0de9c562",
+        },
+    ];
+
+    #[test]
+    fn tests() {
+        HasherTestWrapper::new(EDONR_256::new()).run_tests(EDONR_256_TESTS);
+
+        HasherTestWrapper::new(EDONR_512::new()).run_tests(EDONR_512_TESTS);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test1() {
+        let mut hasher = EDONR_512::new();
+        hasher
+            .update("Not multiple of block size".as_bytes())
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test2() {
+        let mut hasher = EDONR_256::new();
+        let data = [0u8; EDONR_256::BLOCK_SIZE + 1];
+        hasher.update_last(&data).unwrap();
+    }
+}
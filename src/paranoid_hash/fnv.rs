@@ -0,0 +1,267 @@
+use core::mem::size_of;
+
+use crate::{paranoid_hash::Hasher, Error, Result};
+
+macro_rules! create_fnv {
+    ( $struct:ident, $word:ty, $offset:expr, $prime:expr, $alternate:expr ) => {
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $struct {
+            state: $word,
+            is_done: bool,
+            digest: [u8; Self::DIGEST_SIZE],
+        }
+
+        impl $struct {
+            pub const BLOCK_SIZE: usize = 1;
+            pub const DIGEST_SIZE: usize = size_of::<$word>();
+
+            pub const fn new() -> Self {
+                Self {
+                    state: $offset,
+                    is_done: false,
+                    digest: [0; Self::DIGEST_SIZE],
+                }
+            }
+        }
+
+        impl Hasher for $struct {
+            fn update(&mut self, data: &[u8]) -> Result<()> {
+                if self.is_done {
+                    return Err(Error::UpdatingAfterFinished);
+                }
+
+                for c in data {
+                    if $alternate {
+                        self.state ^= *c as $word;
+                        self.state = self.state.wrapping_mul($prime);
+                    } else {
+                        self.state = self.state.wrapping_mul($prime);
+                        self.state ^= *c as $word;
+                    }
+                }
+
+                Ok(())
+            }
+
+            fn update_last(&mut self, data: &[u8]) -> Result<()> {
+                self.update(data)?;
+
+                self.digest = self.state.to_be_bytes();
+
+                self.is_done = true;
+
+                Ok(())
+            }
+
+            fn digest(&self) -> Result<&[u8]> {
+                if !self.is_done {
+                    return Err(Error::NotFinished);
+                }
+
+                Ok(&self.digest)
+            }
+
+            fn reset(&mut self) {
+                *self = Self::new();
+            }
+
+            fn block_size(&self) -> usize {
+                Self::BLOCK_SIZE
+            }
+
+            fn digest_size(&self) -> usize {
+                Self::DIGEST_SIZE
+            }
+
+            impl_state!(stringify!($struct), state, is_done, digest);
+
+            impl_box_clone!();
+        }
+
+        impl Default for $struct {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+create_fnv!(FNV1_32, u32, OFFSET_32, PRIME_32, false);
+create_fnv!(FNV1A_32, u32, OFFSET_32, PRIME_32, true);
+create_fnv!(FNV1_64, u64, OFFSET_64, PRIME_64, false);
+create_fnv!(FNV1A_64, u64, OFFSET_64, PRIME_64, true);
+create_fnv!(FNV1_128, u128, OFFSET_128, PRIME_128, false);
+create_fnv!(FNV1A_128, u128, OFFSET_128, PRIME_128, true);
+
+const OFFSET_32: u32 = 0x811c9dc5;
+const PRIME_32: u32 = 0x01000193;
+const OFFSET_64: u64 = 0xcbf29ce484222325;
+const PRIME_64: u64 = 0x00000100000001b3;
+const OFFSET_128: u128 = 0x6c62272e07bb014262b821756295c58d;
+const PRIME_128: u128 = 0x0000000001000000000000000000013b;
+
+#[cfg(test)]
+mod tests {
+    use crate::paranoid_hash::tester::{HasherTestWrapper, TestData};
+
+    use super::{FNV1A_128, FNV1A_32, FNV1A_64, FNV1_128, FNV1_32, FNV1_64};
+
+    const TEST1: &[u8] = "".as_bytes();
+    const TEST2: &[u8] = "a".as_bytes();
+    const TEST3: &[u8] = "foobar".as_bytes();
+    const TEST4: &[u8] = "chongo was here!\n".as_bytes();
+
+    const FNV1_32_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "811c9dc5",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "050c5d7e",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "31f0b262",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "dd002f35",
+        },
+    ];
+
+    const FNV1A_32_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "811c9dc5",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "e40c292c",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "bf9cf968",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "d49930d5",
+        },
+    ];
+
+    const FNV1_64_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "cbf29ce484222325",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "af63bd4c8601b7be",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "340d8765a4dda9c2",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "e0aca20b624e4235",
+        },
+    ];
+
+    const FNV1A_64_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "cbf29ce484222325",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "af63dc4c8601ec8c",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "85944171f73967e8",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "46810940eff5f915",
+        },
+    ];
+
+    const FNV1_128_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "6c62272e07bb014262b821756295c58d",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "d228cb69101a8caf78912b704e4a141e",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "7896bfea9c3c64bf6dc58353d2c293aa",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "40ab469af9cf0fe57236785215beee65",
+        },
+    ];
+
+    const FNV1A_128_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "6c62272e07bb014262b821756295c58d",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "d228cb696f1a8caf78912b704e4a8964",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "343e1662793c64bf6f0d3597ba446f18",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "d09f538fec03781a034e1e32bab19a75",
+        },
+    ];
+
+    #[test]
+    fn tests() {
+        HasherTestWrapper::new(FNV1_32::new()).run_tests(FNV1_32_TESTS);
+
+        HasherTestWrapper::new(FNV1A_32::new()).run_tests(FNV1A_32_TESTS);
+
+        HasherTestWrapper::new(FNV1_64::new()).run_tests(FNV1_64_TESTS);
+
+        HasherTestWrapper::new(FNV1A_64::new()).run_tests(FNV1A_64_TESTS);
+
+        HasherTestWrapper::new(FNV1_128::new()).run_tests(FNV1_128_TESTS);
+
+        HasherTestWrapper::new(FNV1A_128::new()).run_tests(FNV1A_128_TESTS);
+    }
+}
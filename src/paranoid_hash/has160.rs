@@ -0,0 +1,219 @@
+use core::mem::size_of;
+
+use crate::{paranoid_hash::Hasher, Error, Result};
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HAS160 {
+    state: [u32; 5],
+    count: u64,
+    is_done: bool,
+    digest: [u8; Self::DIGEST_SIZE],
+}
+
+impl Hasher for HAS160 {
+    fn update(&mut self, data: &[u8]) -> Result<()> {
+        transmute_update!(self, data, Self::BLOCK_SIZE, u32, u64, "wrapping", "le");
+    }
+
+    fn update_last(&mut self, data: &[u8]) -> Result<()> {
+        transmute_update_last!(self, data, Self::BLOCK_SIZE, u32, u64, "wrapping", "le");
+    }
+
+    fn digest(&self) -> Result<&[u8]> {
+        if !self.is_done {
+            return Err(Error::NotFinished);
+        }
+
+        Ok(&self.digest)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    fn block_size(&self) -> usize {
+        Self::BLOCK_SIZE
+    }
+
+    fn digest_size(&self) -> usize {
+        Self::DIGEST_SIZE
+    }
+
+    impl_state!("HAS160", state, count, is_done, digest);
+
+    impl_box_clone!();
+}
+
+impl HAS160 {
+    pub const BLOCK_SIZE: usize = 64;
+    pub const DIGEST_SIZE: usize = 20;
+
+    const U32_BLOCK_SIZE: usize = Self::BLOCK_SIZE / size_of::<u32>();
+
+    pub const fn new() -> Self {
+        Self {
+            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0],
+            count: 0,
+            is_done: false,
+            digest: [0; Self::DIGEST_SIZE],
+        }
+    }
+
+    #[inline]
+    fn update_block(&mut self, block: &[u32; Self::U32_BLOCK_SIZE]) {
+        let mut x = [0u32; 32];
+        x[..16].copy_from_slice(block);
+        for (i, indices) in EXTRA_WORDS.iter().enumerate() {
+            x[16 + i] = indices.iter().fold(0, |acc, &j| acc ^ x[j]);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = self.state;
+
+        for round in 0..4 {
+            for step in 0..20 {
+                let f = match round {
+                    0 => d ^ (b & (c ^ d)),
+                    2 => c ^ (b | !d),
+                    _ => b ^ c ^ d,
+                };
+
+                let temp = a
+                    .rotate_left(ROTATIONS[step])
+                    .wrapping_add(f)
+                    .wrapping_add(e)
+                    .wrapping_add(x[WORD_ORDER[round][step]])
+                    .wrapping_add(K[round]);
+
+                e = d;
+                d = c;
+                c = b.rotate_left(B_ROTATIONS[round]);
+                b = a;
+                a = temp;
+            }
+        }
+
+        self.state[0] = self.state[0].wrapping_add(a);
+        self.state[1] = self.state[1].wrapping_add(b);
+        self.state[2] = self.state[2].wrapping_add(c);
+        self.state[3] = self.state[3].wrapping_add(d);
+        self.state[4] = self.state[4].wrapping_add(e);
+    }
+}
+
+impl Default for HAS160 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const K: [u32; 4] = [0x00000000, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc];
+
+const B_ROTATIONS: [u32; 4] = [10, 17, 25, 30];
+
+const ROTATIONS: [u32; 20] = [
+    5, 11, 7, 15, 6, 13, 8, 14, 7, 12, 9, 11, 8, 15, 6, 12, 9, 14, 5, 13,
+];
+
+const EXTRA_WORDS: [[usize; 4]; 16] = [
+    [0, 1, 2, 3],
+    [4, 5, 6, 7],
+    [8, 9, 10, 11],
+    [12, 13, 14, 15],
+    [3, 6, 9, 12],
+    [2, 5, 8, 15],
+    [1, 4, 11, 14],
+    [0, 7, 10, 13],
+    [5, 7, 12, 14],
+    [0, 2, 9, 11],
+    [4, 6, 13, 15],
+    [1, 3, 8, 10],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+];
+
+const WORD_ORDER: [[usize; 20]; 4] = [
+    [
+        18, 0, 1, 2, 3, 19, 4, 5, 6, 7, 16, 8, 9, 10, 11, 17, 12, 13, 14, 15,
+    ],
+    [
+        22, 3, 6, 9, 12, 23, 15, 2, 5, 8, 20, 11, 14, 1, 4, 21, 7, 10, 13, 0,
+    ],
+    [
+        26, 12, 5, 14, 7, 27, 0, 9, 2, 11, 24, 4, 13, 6, 15, 25, 8, 1, 10, 3,
+    ],
+    [
+        30, 7, 2, 13, 8, 31, 3, 14, 9, 4, 28, 15, 10, 5, 0, 29, 11, 6, 1, 12,
+    ],
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::paranoid_hash::{
+        tester::{HasherTestWrapper, TestData},
+        Hasher,
+    };
+
+    use super::HAS160;
+
+    const TESTS: &[TestData] = &[
+        TestData {
+            data: "".as_bytes(),
+            repeat: 1,
+            result: "307964ef34151d37c8047adec7ab50f4ff89762d",
+        },
+        TestData {
+            data: "a".as_bytes(),
+            repeat: 1,
+            result: "4872bcbc4cd0f0a9dc7c2f7045e5b43b6c830db8",
+        },
+        TestData {
+            data: "abc".as_bytes(),
+            repeat: 1,
+            result: "975e810488cf2a3d49838478124afce4b1c78804",
+        },
+        TestData {
+            data: "message digest".as_bytes(),
+            repeat: 1,
+            result: "2338dbc8638d31225f73086246ba529f96710bc6",
+        },
+        TestData {
+            data: "abcdefghijklmnopqrstuvwxyz".as_bytes(),
+            repeat: 1,
+            result: "596185c9ab6703d0d0dbb98702bc0f5729cd1d3c",
+        },
+        TestData {
+            data: "The quick brown fox jumps over the lazy dog".as_bytes(),
+            repeat: 1,
+            result: "abe2b8c711f9e8579aa8eb40757a27b4ef14a7ea",
+        },
+        TestData {
+            data: "a".as_bytes(),
+            repeat: 1000000,
+            result: "d6ad6f0608b878da9b87999c2525cc84f4c9f18d",
+        },
+    ];
+
+    #[test]
+    fn tests() {
+        HasherTestWrapper::new(HAS160::new()).run_tests(TESTS);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test1() {
+        let mut hasher = HAS160::new();
+        hasher
+            .update("Not multiple of block size".as_bytes())
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test2() {
+        let mut hasher = HAS160::new();
+        let data = [0u8; HAS160::BLOCK_SIZE + 1];
+        hasher.update_last(&data).unwrap();
+    }
+}
@@ -1,4 +1,7 @@
-use std::slice;
+// Shared by the algorithm families, some of them are unused when only a few are enabled
+#![cfg_attr(not(feature = "all-algorithms"), allow(dead_code))]
+
+use core::slice;
 
 #[inline(always)]
 pub(super) fn slice_as_chunks<T, const N: usize>(slice: &[T]) -> &[[T; N]] {
@@ -11,3 +14,131 @@ pub(super) fn slice_as_chunks_mut<T, const N: usize>(slice: &mut [T]) -> &mut [[
     debug_assert_eq!(slice.len() % N, 0);
     unsafe { slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len() / N) }
 }
+
+// The helpers below are for the one-shot hashing in const contexts, which can't use iterators,
+// range indexing or trait methods.
+
+/// Pads what follows the last full block of `data` the way MD5, SHA-1 and SHA-2 do, with a bit
+/// length of `length_size` bytes. Returns the padded blocks, one or two of them, and their length.
+pub(super) const fn md_padding_const(
+    data: &[u8],
+    block_size: usize,
+    length_size: usize,
+    big_endian: bool,
+) -> ([u8; 256], usize) {
+    let tail = data.len() % block_size;
+    let mut padded = [0; 256];
+
+    let mut i = 0;
+    while i < tail {
+        padded[i] = data[data.len() - tail + i];
+        i += 1;
+    }
+    padded[tail] = 0x80;
+
+    let length = if tail + 1 + length_size > block_size {
+        block_size * 2
+    } else {
+        block_size
+    };
+
+    let bits = (data.len() as u128 * 8).to_le_bytes();
+    let mut i = 0;
+    while i < length_size {
+        if big_endian {
+            padded[length - 1 - i] = bits[i];
+        } else {
+            padded[length - length_size + i] = bits[i];
+        }
+        i += 1;
+    }
+
+    (padded, length)
+}
+
+/// Reads `N` words starting at `offset`.
+pub(super) const fn read_u32_const<const N: usize>(
+    data: &[u8],
+    offset: usize,
+    big_endian: bool,
+) -> [u32; N] {
+    let mut words = [0; N];
+    let mut i = 0;
+    while i < N {
+        let at = offset + i * 4;
+        let bytes = [data[at], data[at + 1], data[at + 2], data[at + 3]];
+        words[i] = if big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        };
+        i += 1;
+    }
+
+    words
+}
+
+/// Reads `N` words starting at `offset`.
+pub(super) const fn read_u64_const<const N: usize>(
+    data: &[u8],
+    offset: usize,
+    big_endian: bool,
+) -> [u64; N] {
+    let mut words = [0; N];
+    let mut i = 0;
+    while i < N {
+        let at = offset + i * 8;
+        let bytes = [
+            data[at],
+            data[at + 1],
+            data[at + 2],
+            data[at + 3],
+            data[at + 4],
+            data[at + 5],
+            data[at + 6],
+            data[at + 7],
+        ];
+        words[i] = if big_endian {
+            u64::from_be_bytes(bytes)
+        } else {
+            u64::from_le_bytes(bytes)
+        };
+        i += 1;
+    }
+
+    words
+}
+
+/// Writes the words as bytes, truncated to the first `N`.
+pub(super) const fn write_u32_const<const N: usize>(words: &[u32], big_endian: bool) -> [u8; N] {
+    let mut bytes = [0; N];
+    let mut i = 0;
+    while i < N {
+        let word = if big_endian {
+            words[i / 4].to_be_bytes()
+        } else {
+            words[i / 4].to_le_bytes()
+        };
+        bytes[i] = word[i % 4];
+        i += 1;
+    }
+
+    bytes
+}
+
+/// Writes the words as bytes, truncated to the first `N`.
+pub(super) const fn write_u64_const<const N: usize>(words: &[u64], big_endian: bool) -> [u8; N] {
+    let mut bytes = [0; N];
+    let mut i = 0;
+    while i < N {
+        let word = if big_endian {
+            words[i / 8].to_be_bytes()
+        } else {
+            words[i / 8].to_le_bytes()
+        };
+        bytes[i] = word[i % 8];
+        i += 1;
+    }
+
+    bytes
+}
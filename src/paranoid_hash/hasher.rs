@@ -1,26 +1,16 @@
 #![macro_use]
 
+// Signatures in the trait spell out the alloc paths, ambassador copies them to where the trait is
+// delegated.
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+
 use ambassador::delegatable_trait;
 
-use crate::Result;
-
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub enum HasherTag {
-    CRC32,
-    CRC32C,
-    MD2,
-    MD4,
-    MD5,
-    SHA1,
-    SHA2_224,
-    SHA2_256,
-    SHA2_384,
-    SHA2_512,
-    SHA3_224,
-    SHA3_256,
-    SHA3_384,
-    SHA3_512,
-}
+use crate::{
+    paranoid_hash::{verify_digest, Digest},
+    Error, Result,
+};
 
 #[delegatable_trait]
 pub trait Hasher {
@@ -31,4 +21,109 @@ pub trait Hasher {
 
     fn block_size(&self) -> usize;
     fn digest_size(&self) -> usize;
+
+    /// Exports the internal state, hashing can be resumed later by `restore_state`, even in
+    /// another process. The state is tagged with the algorithm and a format version.
+    fn save_state(&self) -> Result<alloc::vec::Vec<u8>> {
+        Err(Error::StateUnsupported)
+    }
+
+    fn restore_state(&mut self, _state: &[u8]) -> Result<()> {
+        Err(Error::StateUnsupported)
+    }
+
+    /// Clones the hasher behind a trait object, e.g. to fork a hash after a common prefix.
+    fn box_clone(&self) -> Result<alloc::boxed::Box<dyn Hasher>> {
+        Err(Error::CloneUnsupported)
+    }
+
+    /// Returns the digest of the data hashed so far by finalizing a copy, this hasher can still be
+    /// updated afterwards.
+    fn peek_digest(&self) -> Result<alloc::vec::Vec<u8>> {
+        match self.digest() {
+            Err(Error::NotFinished) => {
+                let mut hasher = self.box_clone()?;
+                hasher.update_last(&[])?;
+                Ok(hasher.digest()?.to_vec())
+            }
+            digest => Ok(digest?.to_vec()),
+        }
+    }
+
+    /// Compares the digest with the expected one in constant time, see `verify_digest`. An
+    /// unfinished hasher is finalized like `peek_digest` does.
+    fn verify(&self, expected: &[u8]) -> Result<()> {
+        match self.digest() {
+            Err(Error::NotFinished) => verify_digest(expected, &self.peek_digest()?),
+            digest => verify_digest(expected, digest?),
+        }
+    }
+}
+
+impl<H: Hasher + ?Sized> Hasher for Box<H> {
+    fn update(&mut self, data: &[u8]) -> Result<()> {
+        (**self).update(data)
+    }
+
+    fn update_last(&mut self, data: &[u8]) -> Result<()> {
+        (**self).update_last(data)
+    }
+
+    fn digest(&self) -> Result<&[u8]> {
+        (**self).digest()
+    }
+
+    fn reset(&mut self) {
+        (**self).reset()
+    }
+
+    fn block_size(&self) -> usize {
+        (**self).block_size()
+    }
+
+    fn digest_size(&self) -> usize {
+        (**self).digest_size()
+    }
+
+    fn save_state(&self) -> Result<Vec<u8>> {
+        (**self).save_state()
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> Result<()> {
+        (**self).restore_state(state)
+    }
+
+    fn box_clone(&self) -> Result<Box<dyn Hasher>> {
+        (**self).box_clone()
+    }
+
+    fn peek_digest(&self) -> Result<Vec<u8>> {
+        (**self).peek_digest()
+    }
+
+    fn verify(&self, expected: &[u8]) -> Result<()> {
+        (**self).verify(expected)
+    }
+}
+
+impl<'a> fmt::Debug for dyn Hasher + 'a {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.digest().and_then(Digest::new))
+    }
+}
+
+impl<'a> fmt::Debug for dyn Hasher + Send + 'a {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self as &dyn Hasher).fmt(f)
+    }
+}
+
+/// Hashers which accept messages whose length is not a multiple of 8 bits.
+#[delegatable_trait]
+pub trait BitHasher: Hasher {
+    /// Like `update_last`, but only the first `bit_len` bits of `data` are hashed, `data` must be
+    /// exactly `bit_len` bits rounded up to bytes. The bits of the last partial byte are placed
+    /// where the algorithm's standard puts them, the most significant bits for SHA-1 and SHA-2,
+    /// the least significant bits for SHA-3, other bits of that byte are ignored.
+    fn update_last_bits(&mut self, data: &[u8], bit_len: usize) -> Result<()>;
 }
@@ -0,0 +1,412 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::{fmt, str::FromStr};
+
+use crate::{paranoid_hash::Hasher, Error};
+
+struct TagInfo {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    oid: Option<&'static str>,
+    multihash: Option<u64>,
+    digest_size: usize,
+    block_size: usize,
+    is_cryptographic: bool,
+    is_broken: bool,
+}
+
+// Tags are grouped by the cargo feature of their algorithm family, every tag is listed with its
+// hasher type, canonical name, aliases, ASN.1 OID, multihash code, and whether it is a
+// cryptographic hash function and whether it is broken.
+macro_rules! hasher_tags {
+    ( $( $feature:literal { $( $tag:ident, $type:ident, $name:literal, [ $( $alias:literal ),* ], $oid:expr, $multihash:expr, $crypto:literal, $broken:literal; )* } )* ) => {
+        /// Built-in algorithms, only the ones whose family feature is enabled exist.
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        pub enum HasherTag {
+            $( $( #[cfg(feature = $feature)] $tag, )* )*
+        }
+
+        impl HasherTag {
+            pub const fn all() -> &'static [HasherTag] {
+                &[ $( $( #[cfg(feature = $feature)] HasherTag::$tag, )* )* ]
+            }
+
+            /// Creates a hasher of the built-in implementation.
+            pub fn new_hasher(self) -> Box<dyn Hasher> {
+                match self {
+                    $( $( #[cfg(feature = $feature)] HasherTag::$tag => Box::new(crate::paranoid_hash::$type::new()), )* )*
+                }
+            }
+
+            /// Same as `new_hasher`, the built-in hashers can all be sent to other threads.
+            pub(crate) fn new_send_hasher(self) -> Box<dyn Hasher + Send> {
+                match self {
+                    $( $( #[cfg(feature = $feature)] HasherTag::$tag => Box::new(crate::paranoid_hash::$type::new()), )* )*
+                }
+            }
+
+            const fn info(self) -> TagInfo {
+                match self {
+                    $( $(
+                        #[cfg(feature = $feature)]
+                        HasherTag::$tag => TagInfo {
+                            name: $name,
+                            aliases: &[ $( $alias ),* ],
+                            oid: $oid,
+                            multihash: $multihash,
+                            digest_size: crate::paranoid_hash::$type::DIGEST_SIZE,
+                            block_size: crate::paranoid_hash::$type::BLOCK_SIZE,
+                            is_cryptographic: $crypto,
+                            is_broken: $broken,
+                        },
+                    )* )*
+                }
+            }
+        }
+    };
+}
+
+hasher_tags! {
+    "crc32" {
+        CRC32, CRC32, "crc32", ["crc-32"], None, Some(0x0132), false, true;
+        CRC32C, CRC32C, "crc32c", ["crc-32c", "castagnoli"], None, None, false, true;
+    }
+    "md2" {
+        MD2, MD2, "md2", [], Some("1.2.840.113549.2.2"), None, true, true;
+    }
+    "md4" {
+        MD4, MD4, "md4", [], Some("1.2.840.113549.2.4"), Some(0xd4), true, true;
+    }
+    "md5" {
+        MD5, MD5, "md5", [], Some("1.2.840.113549.2.5"), Some(0xd5), true, true;
+    }
+    "sha1" {
+        SHA1, SHA1, "sha1", ["sha-1"], Some("1.3.14.3.2.26"), Some(0x11), true, true;
+    }
+    "sha1dc" {
+        SHA1DC, SHA1DC, "sha1dc", ["sha-1dc"], Some("1.3.14.3.2.26"), Some(0x11), true, true;
+    }
+    "sha2" {
+        SHA2_224, SHA2_224, "sha2-224", ["sha224", "sha-224"], Some("2.16.840.1.101.3.4.2.4"), Some(0x1013), true, false;
+        SHA2_256, SHA2_256, "sha2-256", ["sha256", "sha-256"], Some("2.16.840.1.101.3.4.2.1"), Some(0x12), true, false;
+        SHA2_384, SHA2_384, "sha2-384", ["sha384", "sha-384"], Some("2.16.840.1.101.3.4.2.2"), Some(0x20), true, false;
+        SHA2_512, SHA2_512, "sha2-512", ["sha512", "sha-512"], Some("2.16.840.1.101.3.4.2.3"), Some(0x13), true, false;
+    }
+    "sha3" {
+        SHA3_224, SHA3_224, "sha3-224", [], Some("2.16.840.1.101.3.4.2.7"), Some(0x17), true, false;
+        SHA3_256, SHA3_256, "sha3-256", [], Some("2.16.840.1.101.3.4.2.8"), Some(0x16), true, false;
+        SHA3_384, SHA3_384, "sha3-384", [], Some("2.16.840.1.101.3.4.2.9"), Some(0x15), true, false;
+        SHA3_512, SHA3_512, "sha3-512", [], Some("2.16.840.1.101.3.4.2.10"), Some(0x14), true, false;
+    }
+    "murmur3" {
+        MURMUR3_X86_32, MURMUR3_X86_32, "murmur3-x86-32", ["murmur3-32"], None, Some(0x23), false, true;
+        MURMUR3_X64_128, MURMUR3_X64_128, "murmur3-x64-128", ["murmur3-128"], None, Some(0x1022), false, true;
+    }
+    "fnv" {
+        FNV1_32, FNV1_32, "fnv1-32", [], None, None, false, true;
+        FNV1A_32, FNV1A_32, "fnv1a-32", [], None, None, false, true;
+        FNV1_64, FNV1_64, "fnv1-64", [], None, None, false, true;
+        FNV1A_64, FNV1A_64, "fnv1a-64", [], None, None, false, true;
+        FNV1_128, FNV1_128, "fnv1-128", [], None, None, false, true;
+        FNV1A_128, FNV1A_128, "fnv1a-128", [], None, None, false, true;
+    }
+    "haval" {
+        HAVAL_128_3, HAVAL_128_3, "haval-128-3", [], None, None, true, true;
+        HAVAL_160_3, HAVAL_160_3, "haval-160-3", [], None, None, true, true;
+        HAVAL_192_3, HAVAL_192_3, "haval-192-3", [], None, None, true, true;
+        HAVAL_224_3, HAVAL_224_3, "haval-224-3", [], None, None, true, true;
+        HAVAL_256_3, HAVAL_256_3, "haval-256-3", [], None, None, true, true;
+        HAVAL_128_4, HAVAL_128_4, "haval-128-4", [], None, None, true, true;
+        HAVAL_160_4, HAVAL_160_4, "haval-160-4", [], None, None, true, true;
+        HAVAL_192_4, HAVAL_192_4, "haval-192-4", [], None, None, true, true;
+        HAVAL_224_4, HAVAL_224_4, "haval-224-4", [], None, None, true, true;
+        HAVAL_256_4, HAVAL_256_4, "haval-256-4", [], None, None, true, true;
+        HAVAL_128_5, HAVAL_128_5, "haval-128-5", [], None, None, true, false;
+        HAVAL_160_5, HAVAL_160_5, "haval-160-5", [], None, None, true, false;
+        HAVAL_192_5, HAVAL_192_5, "haval-192-5", [], None, None, true, false;
+        HAVAL_224_5, HAVAL_224_5, "haval-224-5", [], None, None, true, false;
+        HAVAL_256_5, HAVAL_256_5, "haval-256-5", [], None, None, true, false;
+    }
+    "snefru" {
+        SNEFRU_128, SNEFRU_128, "snefru-128", [], None, None, true, true;
+        SNEFRU_256, SNEFRU_256, "snefru-256", [], None, None, true, true;
+    }
+    "has160" {
+        HAS160, HAS160, "has160", ["has-160"], Some("1.2.410.200004.1.2"), None, true, false;
+    }
+    "edonr" {
+        EDONR_256, EDONR_256, "edonr-256", ["edon-r256"], None, None, true, false;
+        EDONR_512, EDONR_512, "edonr-512", ["edon-r512"], None, None, true, false;
+    }
+}
+
+impl HasherTag {
+    /// Canonical lowercase name, e.g. "sha2-256".
+    pub const fn name(self) -> &'static str {
+        self.info().name
+    }
+
+    /// Other common names, parsing accepts them as well.
+    pub const fn aliases(self) -> &'static [&'static str] {
+        self.info().aliases
+    }
+
+    /// Dotted ASN.1 object identifier of the algorithm, if there's one.
+    pub const fn oid(self) -> Option<&'static str> {
+        self.info().oid
+    }
+
+    /// Code of the algorithm in the multicodec table, if there's one.
+    pub const fn multihash_code(self) -> Option<u64> {
+        self.info().multihash
+    }
+
+    pub const fn digest_size(self) -> usize {
+        self.info().digest_size
+    }
+
+    pub const fn block_size(self) -> usize {
+        self.info().block_size
+    }
+
+    /// Checksums and non-cryptographic hashes like CRC, FNV and Murmur return false.
+    pub const fn is_cryptographic(self) -> bool {
+        self.info().is_cryptographic
+    }
+
+    /// Whether the algorithm must not be relied on against attackers, either because practical
+    /// attacks are known or because it is not cryptographic at all.
+    pub const fn is_broken(self) -> bool {
+        self.info().is_broken
+    }
+
+    /// Resistance against collision attacks, see `SecurityStrength`.
+    pub const fn security_strength(self) -> SecurityStrength {
+        if !self.is_cryptographic() {
+            SecurityStrength::NonCryptographic
+        } else if self.is_broken() {
+            SecurityStrength::Broken
+        } else {
+            SecurityStrength::Bits((self.digest_size() * 8 / 2) as u16)
+        }
+    }
+
+    pub const fn is_weak(self) -> bool {
+        self.security_strength().is_weak()
+    }
+
+    pub fn from_oid(oid: &str) -> Option<HasherTag> {
+        Self::all().iter().copied().find(|t| t.oid() == Some(oid))
+    }
+
+    pub fn from_multihash_code(code: u64) -> Option<HasherTag> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|t| t.multihash_code() == Some(code))
+    }
+}
+
+/// How well an algorithm resists collision attacks, ordered from the weakest to the strongest.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SecurityStrength {
+    /// Checksums and the like, they only detect accidental changes.
+    NonCryptographic,
+    /// Practical collision attacks are known.
+    Broken,
+    /// Collision resistance in bits, which is half of the digest size.
+    Bits(u16),
+}
+
+impl SecurityStrength {
+    /// Minimum security strength allowed by NIST SP 800-57.
+    pub const MINIMUM_BITS: u16 = 112;
+
+    /// Anything below `MINIMUM_BITS` is weak.
+    pub const fn is_weak(self) -> bool {
+        !matches!(self, SecurityStrength::Bits(bits) if bits >= Self::MINIMUM_BITS)
+    }
+}
+
+impl fmt::Display for HasherTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for HasherTag {
+    type Err = Error;
+
+    /// Names are case insensitive, and '-', '_' and spaces are ignored, so "SHA-256", "sha256",
+    /// "sha2-256" and "SHA2_256" are all the same.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = normalize_name(s);
+        Self::all()
+            .iter()
+            .copied()
+            .find(|tag| {
+                core::iter::once(tag.name())
+                    .chain(tag.aliases().iter().copied())
+                    .any(|n| normalize_name(n) == name)
+            })
+            .ok_or_else(|| Error::UnknownAlgorithm(s.to_string()))
+    }
+}
+
+pub(crate) fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    use crate::paranoid_hash::{HasherTag, SecurityStrength};
+
+    #[test]
+    fn name_tests() {
+        for tag in HasherTag::all() {
+            assert_eq!(tag.to_string().parse::<HasherTag>().unwrap(), *tag);
+            assert_eq!(format!("{:?}", tag).parse::<HasherTag>().unwrap(), *tag);
+            for alias in tag.aliases() {
+                assert_eq!(alias.parse::<HasherTag>().unwrap(), *tag);
+            }
+        }
+
+        #[cfg(feature = "sha2")]
+        for name in ["sha256", "SHA-256", "sha2-256", "SHA2_256", "Sha 256"] {
+            assert_eq!(name.parse::<HasherTag>().unwrap(), HasherTag::SHA2_256);
+        }
+
+        #[cfg(feature = "sha3")]
+        assert_eq!(HasherTag::SHA3_256.to_string(), "sha3-256");
+        assert!("sha3".parse::<HasherTag>().is_err());
+        assert!("".parse::<HasherTag>().is_err());
+    }
+
+    #[test]
+    fn metadata_tests() {
+        // number of tags of every family feature
+        let families = [
+            (cfg!(feature = "crc32"), 2),
+            (cfg!(feature = "md2"), 1),
+            (cfg!(feature = "md4"), 1),
+            (cfg!(feature = "md5"), 1),
+            (cfg!(feature = "sha1"), 1),
+            (cfg!(feature = "sha1dc"), 1),
+            (cfg!(feature = "sha2"), 4),
+            (cfg!(feature = "sha3"), 4),
+            (cfg!(feature = "murmur3"), 2),
+            (cfg!(feature = "fnv"), 6),
+            (cfg!(feature = "haval"), 15),
+            (cfg!(feature = "snefru"), 2),
+            (cfg!(feature = "has160"), 1),
+            (cfg!(feature = "edonr"), 2),
+        ];
+        let expected: usize = families
+            .iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, count)| count)
+            .sum();
+        assert_eq!(HasherTag::all().len(), expected);
+
+        #[cfg(feature = "crc32")]
+        {
+            assert_eq!(HasherTag::all()[0], HasherTag::CRC32);
+            assert!(HasherTag::CRC32.is_broken());
+            assert!(!HasherTag::CRC32.is_cryptographic());
+            assert_eq!(
+                HasherTag::CRC32.security_strength(),
+                SecurityStrength::NonCryptographic
+            );
+        }
+
+        #[cfg(feature = "md5")]
+        {
+            assert_eq!(
+                HasherTag::from_oid("1.2.840.113549.2.5"),
+                Some(HasherTag::MD5)
+            );
+            assert!(HasherTag::MD5.is_broken());
+            assert!(HasherTag::MD5.is_cryptographic());
+            assert_eq!(HasherTag::MD5.security_strength(), SecurityStrength::Broken);
+        }
+        assert_eq!(HasherTag::from_oid("1.2.3"), None);
+
+        #[cfg(feature = "sha1")]
+        assert!(HasherTag::SHA1.is_broken());
+
+        #[cfg(feature = "sha2")]
+        {
+            assert_eq!(HasherTag::SHA2_256.oid(), Some("2.16.840.1.101.3.4.2.1"));
+            assert_eq!(HasherTag::SHA2_256.multihash_code(), Some(0x12));
+            assert_eq!(HasherTag::SHA2_384.digest_size(), 48);
+            assert_eq!(HasherTag::SHA2_384.block_size(), 128);
+            assert!(!HasherTag::SHA2_256.is_broken());
+            assert_eq!(
+                HasherTag::SHA2_224.security_strength(),
+                SecurityStrength::Bits(112)
+            );
+            assert!(HasherTag::SHA2_384.security_strength() > SecurityStrength::Bits(128));
+            assert!(!HasherTag::SHA2_224.is_weak());
+        }
+
+        #[cfg(feature = "sha3")]
+        {
+            assert_eq!(
+                HasherTag::from_multihash_code(0x16),
+                Some(HasherTag::SHA3_256)
+            );
+            assert_eq!(HasherTag::SHA3_256.block_size(), 136);
+        }
+
+        #[cfg(feature = "snefru")]
+        {
+            assert!(HasherTag::SNEFRU_128.is_weak());
+            assert_eq!(
+                HasherTag::SNEFRU_256.security_strength(),
+                SecurityStrength::Broken
+            );
+        }
+
+        for tag in HasherTag::all() {
+            assert_eq!(tag.is_weak(), tag.is_broken() || tag.digest_size() < 28);
+        }
+    }
+
+    #[cfg(feature = "haval")]
+    #[test]
+    fn haval_broken_tests() {
+        // attacks on a pass count break the whole state, so every output size of it
+        for passes in ["3", "4", "5"] {
+            let tags: Vec<_> = HasherTag::all()
+                .iter()
+                .filter(|t| t.name().starts_with("haval-") && t.name().ends_with(passes))
+                .collect();
+            assert_eq!(tags.len(), 5);
+            assert!(tags.iter().all(|t| t.is_broken() == tags[0].is_broken()));
+        }
+
+        assert!(HasherTag::HAVAL_256_4.is_broken());
+        assert!(!HasherTag::HAVAL_256_5.is_broken());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn wrapper_size_tests() {
+        use crate::hasher_server::HasherWrapper;
+
+        for tag in HasherTag::all() {
+            let wrapper = HasherWrapper::create_from_tag(*tag);
+            let hasher = wrapper.hasher.get_mut();
+            assert_eq!(hasher.digest_size(), tag.digest_size());
+            assert_eq!(hasher.block_size(), tag.block_size());
+        }
+    }
+}
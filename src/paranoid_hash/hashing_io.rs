@@ -0,0 +1,184 @@
+use std::io::{Read, Write};
+
+use crate::{
+    paranoid_hash::{BufferedHasher, Hasher},
+    Error, Result,
+};
+
+/// Updates the hashers with every byte read from the inner reader.
+///
+/// Hashing errors don't fail `read`, the data is consumed already, `finalize` returns them.
+pub struct HashingReader<R: Read, H: Hasher = Box<dyn Hasher>> {
+    inner: R,
+    hashers: Vec<BufferedHasher<H>>,
+    error: Option<Error>,
+}
+
+/// Updates the hashers with every byte written to the inner writer.
+///
+/// Hashing errors don't fail `write`, the data is written already, `finalize` returns them.
+pub struct HashingWriter<W: Write, H: Hasher = Box<dyn Hasher>> {
+    inner: W,
+    hashers: Vec<BufferedHasher<H>>,
+    error: Option<Error>,
+}
+
+macro_rules! impl_common {
+    ( $struct:ident, $inner:ident, $trait:ident ) => {
+        impl<$inner: $trait, H: Hasher> $struct<$inner, H> {
+            pub fn new(inner: $inner, hasher: H) -> Self {
+                Self::with_hashers(inner, [hasher])
+            }
+
+            pub fn with_hashers(inner: $inner, hashers: impl IntoIterator<Item = H>) -> Self {
+                Self {
+                    inner,
+                    hashers: hashers.into_iter().map(BufferedHasher::new).collect(),
+                    error: None,
+                }
+            }
+
+            pub fn get_ref(&self) -> &$inner {
+                &self.inner
+            }
+
+            /// Data passed through the returned reference directly is not hashed.
+            pub fn get_mut(&mut self) -> &mut $inner {
+                &mut self.inner
+            }
+
+            pub fn hashers(&self) -> &[BufferedHasher<H>] {
+                &self.hashers
+            }
+
+            /// Finalizes all the hashers, the digests are in the same order as the hashers.
+            ///
+            /// Fails with the first error of updating the hashers if there was one.
+            pub fn finalize(&mut self) -> Result<Vec<Vec<u8>>> {
+                let digests: Vec<_> = self
+                    .hashers
+                    .iter_mut()
+                    .map(|h| h.finalize().map(|d| d.to_vec()))
+                    .collect();
+
+                if let Some(error) = self.error.take() {
+                    return Err(error);
+                }
+
+                digests.into_iter().collect()
+            }
+
+            pub fn into_parts(self) -> ($inner, Vec<BufferedHasher<H>>) {
+                (self.inner, self.hashers)
+            }
+
+            // The digests are wrong after an error, so the hashers are left alone from then on.
+            fn update(&mut self, data: &[u8]) {
+                if self.error.is_some() {
+                    return;
+                }
+
+                for hasher in self.hashers.iter_mut() {
+                    if let Err(error) = hasher.update(data) {
+                        self.error = Some(error);
+                        return;
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_common!(HashingReader, R, Read);
+impl_common!(HashingWriter, W, Write);
+
+impl<R: Read, H: Hasher> Read for HashingReader<R, H> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let length = self.inner.read(buf)?;
+        self.update(&buf[..length]);
+        Ok(length)
+    }
+}
+
+impl<W: Write, H: Hasher> Write for HashingWriter<W, H> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let length = self.inner.write(buf)?;
+        self.update(&buf[..length]);
+        Ok(length)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    use std::io::{Read, Write};
+
+    #[cfg(feature = "sha2")]
+    use crate::paranoid_hash::SHA2_256;
+    #[cfg(all(feature = "md5", feature = "sha1"))]
+    use crate::paranoid_hash::{Hasher, MD5, SHA1};
+    use crate::Error;
+
+    use super::{HashingReader, HashingWriter};
+
+    const DATA: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+    const MD5_RESULT: &str = "9e107d9d372bb6826bd81d3542a419d6";
+    const SHA1_RESULT: &str = "2fd4e1c67a2d28fced849ee1bb76e7391b93eb12";
+
+    #[cfg(all(feature = "md5", feature = "sha1"))]
+    #[test]
+    fn reader_test() {
+        let hashers: Vec<Box<dyn Hasher>> = vec![Box::new(MD5::new()), Box::new(SHA1::new())];
+        let mut reader = HashingReader::with_hashers(DATA, hashers);
+
+        let mut output = vec![];
+        let mut buffer = [0u8; 5];
+        loop {
+            let length = reader.read(&mut buffer).unwrap();
+            if length == 0 {
+                break;
+            }
+            output.extend_from_slice(&buffer[..length]);
+        }
+
+        assert_eq!(output, DATA);
+        assert_eq!(
+            reader.finalize().unwrap(),
+            [
+                hex::decode(MD5_RESULT).unwrap(),
+                hex::decode(SHA1_RESULT).unwrap()
+            ]
+        );
+        assert!(reader.finalize().is_err());
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn writer_test() {
+        let mut writer = HashingWriter::new(vec![], SHA2_256::new());
+        writer.write_all(&DATA[..10]).unwrap();
+        std::io::copy(&mut &DATA[10..], &mut writer).unwrap();
+        writer.flush().unwrap();
+
+        let digests = writer.finalize().unwrap();
+        assert_eq!(
+            hex::encode(&digests[0]),
+            "d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592"
+        );
+
+        // the data still reaches the writer when hashing fails, finalize reports the error
+        assert_eq!(writer.write(DATA).unwrap(), DATA.len());
+        assert!(matches!(
+            writer.finalize(),
+            Err(Error::UpdatingAfterFinished)
+        ));
+
+        let (output, _) = writer.into_parts();
+        assert_eq!(output, DATA.repeat(2));
+    }
+}
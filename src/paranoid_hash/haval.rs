@@ -0,0 +1,980 @@
+use core::mem::size_of;
+
+use crate::{paranoid_hash::Hasher, Error, Result};
+
+macro_rules! create_haval {
+    ( $struct:ident, $digest_size:expr, $passes:expr ) => {
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $struct {
+            state: [u32; 8],
+            count: u64,
+            is_done: bool,
+            digest: [u8; Self::DIGEST_SIZE],
+        }
+
+        impl Hasher for $struct {
+            fn update(&mut self, data: &[u8]) -> Result<()> {
+                transmute_update!(self, data, Self::BLOCK_SIZE, u32, u64, "wrapping", "le");
+            }
+
+            fn update_last(&mut self, data: &[u8]) -> Result<()> {
+                if self.is_done {
+                    return Err(Error::UpdatingAfterFinished);
+                }
+
+                if data.len() > Self::BLOCK_SIZE {
+                    return Err(Error::DataTooLarge(data.len(), Self::BLOCK_SIZE));
+                }
+
+                self.count = self.count.wrapping_add((data.len() as u64).wrapping_mul(8));
+
+                let mut final_block = [0u8; Self::BLOCK_SIZE * 2];
+                final_block[..data.len()].copy_from_slice(data);
+                final_block[data.len()] = PADDING;
+
+                let count = self.count.to_le_bytes();
+                let trailer_length = TRAILER_LENGTH + count.len();
+
+                let final_block_slice = if data.len() + trailer_length < Self::BLOCK_SIZE {
+                    &mut final_block[..Self::BLOCK_SIZE]
+                } else {
+                    &mut final_block[..]
+                };
+                let length = final_block_slice.len();
+                final_block_slice[length - trailer_length] =
+                    ((Self::DIGEST_SIZE * 8 & 0b11) << 6 | ($passes & 0b111) << 3 | VERSION) as u8;
+                final_block_slice[length - trailer_length + 1] = (Self::DIGEST_SIZE * 8 >> 2) as u8;
+                final_block_slice[length - count.len()..].copy_from_slice(&count);
+
+                self.update(final_block_slice)?;
+
+                let state = tailor(self.state, Self::DIGEST_SIZE);
+                for (d, s) in self
+                    .digest
+                    .chunks_exact_mut(size_of::<u32>())
+                    .zip(state.iter())
+                {
+                    d.copy_from_slice(&s.to_le_bytes());
+                }
+
+                self.is_done = true;
+
+                Ok(())
+            }
+
+            fn digest(&self) -> Result<&[u8]> {
+                if !self.is_done {
+                    return Err(Error::NotFinished);
+                }
+
+                Ok(&self.digest)
+            }
+
+            fn reset(&mut self) {
+                *self = Self::new();
+            }
+
+            fn block_size(&self) -> usize {
+                Self::BLOCK_SIZE
+            }
+
+            fn digest_size(&self) -> usize {
+                Self::DIGEST_SIZE
+            }
+
+            impl_state!(stringify!($struct), state, count, is_done, digest);
+
+            impl_box_clone!();
+        }
+
+        impl $struct {
+            pub const BLOCK_SIZE: usize = 128;
+            pub const DIGEST_SIZE: usize = $digest_size;
+
+            const U32_BLOCK_SIZE: usize = Self::BLOCK_SIZE / size_of::<u32>();
+
+            pub const fn new() -> Self {
+                let mut state = [0; 8];
+                let mut i = 0;
+                while i < state.len() {
+                    state[i] = PI[i];
+                    i += 1;
+                }
+
+                Self {
+                    state,
+                    count: 0,
+                    is_done: false,
+                    digest: [0; Self::DIGEST_SIZE],
+                }
+            }
+
+            #[inline]
+            fn update_block(&mut self, block: &[u32; Self::U32_BLOCK_SIZE]) {
+                compress(&mut self.state, block, $passes);
+            }
+        }
+
+        impl Default for $struct {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+create_haval!(HAVAL_128_3, 16, 3);
+create_haval!(HAVAL_160_3, 20, 3);
+create_haval!(HAVAL_192_3, 24, 3);
+create_haval!(HAVAL_224_3, 28, 3);
+create_haval!(HAVAL_256_3, 32, 3);
+create_haval!(HAVAL_128_4, 16, 4);
+create_haval!(HAVAL_160_4, 20, 4);
+create_haval!(HAVAL_192_4, 24, 4);
+create_haval!(HAVAL_224_4, 28, 4);
+create_haval!(HAVAL_256_4, 32, 4);
+create_haval!(HAVAL_128_5, 16, 5);
+create_haval!(HAVAL_160_5, 20, 5);
+create_haval!(HAVAL_192_5, 24, 5);
+create_haval!(HAVAL_224_5, 28, 5);
+create_haval!(HAVAL_256_5, 32, 5);
+
+const PADDING: u8 = 0x01;
+const VERSION: usize = 0x01;
+const TRAILER_LENGTH: usize = 2;
+
+#[inline(always)]
+fn compress(state: &mut [u32; 8], block: &[u32; 32], passes: usize) {
+    let permutations: &[[usize; 7]] = match passes {
+        3 => &PHI_3,
+        4 => &PHI_4,
+        _ => &PHI_5,
+    };
+
+    let mut t = *state;
+
+    for (pass, permutation) in permutations.iter().enumerate() {
+        for i in 0..32 {
+            // x[j] is the register currently holding x_j
+            let x = |j: usize| t[(j + 8 - i % 8) % 8];
+
+            let args = permutation.map(x);
+            let f = match pass {
+                0 => f1(args),
+                1 => f2(args),
+                2 => f3(args),
+                3 => f4(args),
+                _ => f5(args),
+            };
+
+            let k = if pass == 0 {
+                0
+            } else {
+                PI[8 + 32 * (pass - 1) + i]
+            };
+
+            t[(15 - i % 8) % 8] = f
+                .rotate_right(7)
+                .wrapping_add(x(7).rotate_right(11))
+                .wrapping_add(block[WORD_ORDER[pass][i]])
+                .wrapping_add(k);
+        }
+    }
+
+    for (s, t) in state.iter_mut().zip(t.iter()) {
+        *s = s.wrapping_add(*t);
+    }
+}
+
+#[inline(always)]
+fn f1([x6, x5, x4, x3, x2, x1, x0]: [u32; 7]) -> u32 {
+    x1 & (x0 ^ x4) ^ x2 & x5 ^ x3 & x6 ^ x0
+}
+
+#[inline(always)]
+fn f2([x6, x5, x4, x3, x2, x1, x0]: [u32; 7]) -> u32 {
+    x2 & (x1 & !x3 ^ x4 & x5 ^ x6 ^ x0) ^ x4 & (x1 ^ x5) ^ x3 & x5 ^ x0
+}
+
+#[inline(always)]
+fn f3([x6, x5, x4, x3, x2, x1, x0]: [u32; 7]) -> u32 {
+    x3 & (x1 & x2 ^ x6 ^ x0) ^ x1 & x4 ^ x2 & x5 ^ x0
+}
+
+#[inline(always)]
+fn f4([x6, x5, x4, x3, x2, x1, x0]: [u32; 7]) -> u32 {
+    x4 & (x5 & !x2 ^ x3 & !x6 ^ x1 ^ x6 ^ x0) ^ x3 & (x1 & x2 ^ x5 ^ x6) ^ x2 & x6 ^ x0
+}
+
+#[inline(always)]
+fn f5([x6, x5, x4, x3, x2, x1, x0]: [u32; 7]) -> u32 {
+    x0 & (x1 & x2 & x3 ^ !x5) ^ x1 & x4 ^ x2 & x5 ^ x3 & x6
+}
+
+/// Folds the 256-bit state into the requested digest size.
+#[inline(always)]
+fn tailor(mut t: [u32; 8], digest_size: usize) -> [u32; 8] {
+    match digest_size * 8 {
+        128 => {
+            let temp =
+                t[7] & 0x000000ff | t[6] & 0xff000000 | t[5] & 0x00ff0000 | t[4] & 0x0000ff00;
+            t[0] = t[0].wrapping_add(temp.rotate_right(8));
+            let temp =
+                t[7] & 0x0000ff00 | t[6] & 0x000000ff | t[5] & 0xff000000 | t[4] & 0x00ff0000;
+            t[1] = t[1].wrapping_add(temp.rotate_right(16));
+            let temp =
+                t[7] & 0x00ff0000 | t[6] & 0x0000ff00 | t[5] & 0x000000ff | t[4] & 0xff000000;
+            t[2] = t[2].wrapping_add(temp.rotate_right(24));
+            let temp =
+                t[7] & 0xff000000 | t[6] & 0x00ff0000 | t[5] & 0x0000ff00 | t[4] & 0x000000ff;
+            t[3] = t[3].wrapping_add(temp);
+        }
+        160 => {
+            let temp = t[7] & 0x3f | t[6] & (0x7f << 25) | t[5] & (0x3f << 19);
+            t[0] = t[0].wrapping_add(temp.rotate_right(19));
+            let temp = t[7] & (0x3f << 6) | t[6] & 0x3f | t[5] & (0x7f << 25);
+            t[1] = t[1].wrapping_add(temp.rotate_right(25));
+            let temp = t[7] & (0x7f << 12) | t[6] & (0x3f << 6) | t[5] & 0x3f;
+            t[2] = t[2].wrapping_add(temp);
+            let temp = t[7] & (0x3f << 19) | t[6] & (0x7f << 12) | t[5] & (0x3f << 6);
+            t[3] = t[3].wrapping_add(temp >> 6);
+            let temp = t[7] & (0x7f << 25) | t[6] & (0x3f << 19) | t[5] & (0x7f << 12);
+            t[4] = t[4].wrapping_add(temp >> 12);
+        }
+        192 => {
+            let temp = t[7] & 0x1f | t[6] & (0x3f << 26);
+            t[0] = t[0].wrapping_add(temp.rotate_right(26));
+            let temp = t[7] & (0x1f << 5) | t[6] & 0x1f;
+            t[1] = t[1].wrapping_add(temp);
+            let temp = t[7] & (0x3f << 10) | t[6] & (0x1f << 5);
+            t[2] = t[2].wrapping_add(temp >> 5);
+            let temp = t[7] & (0x1f << 16) | t[6] & (0x3f << 10);
+            t[3] = t[3].wrapping_add(temp >> 10);
+            let temp = t[7] & (0x1f << 21) | t[6] & (0x1f << 16);
+            t[4] = t[4].wrapping_add(temp >> 16);
+            let temp = t[7] & (0x3f << 26) | t[6] & (0x1f << 21);
+            t[5] = t[5].wrapping_add(temp >> 21);
+        }
+        224 => {
+            t[0] = t[0].wrapping_add(t[7] >> 27 & 0x1f);
+            t[1] = t[1].wrapping_add(t[7] >> 22 & 0x1f);
+            t[2] = t[2].wrapping_add(t[7] >> 18 & 0x0f);
+            t[3] = t[3].wrapping_add(t[7] >> 13 & 0x1f);
+            t[4] = t[4].wrapping_add(t[7] >> 9 & 0x0f);
+            t[5] = t[5].wrapping_add(t[7] >> 4 & 0x1f);
+            t[6] = t[6].wrapping_add(t[7] & 0x0f);
+        }
+        _ => {}
+    }
+
+    t
+}
+
+const PHI_3: [[usize; 7]; 3] = [
+    [1, 0, 3, 5, 6, 2, 4],
+    [4, 2, 1, 0, 5, 3, 6],
+    [6, 1, 2, 3, 4, 5, 0],
+];
+
+const PHI_4: [[usize; 7]; 4] = [
+    [2, 6, 1, 4, 5, 3, 0],
+    [3, 5, 2, 0, 1, 6, 4],
+    [1, 4, 3, 6, 0, 2, 5],
+    [6, 4, 0, 5, 2, 1, 3],
+];
+
+const PHI_5: [[usize; 7]; 5] = [
+    [3, 4, 1, 0, 5, 2, 6],
+    [6, 2, 1, 0, 3, 4, 5],
+    [2, 6, 0, 4, 3, 1, 5],
+    [1, 5, 3, 2, 0, 4, 6],
+    [2, 5, 0, 6, 4, 3, 1],
+];
+
+const WORD_ORDER: [[usize; 32]; 5] = [
+    [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31,
+    ],
+    [
+        5, 14, 26, 18, 11, 28, 7, 16, 0, 23, 20, 22, 1, 10, 4, 8, 30, 3, 21, 9, 17, 24, 29, 6, 19,
+        12, 15, 13, 2, 25, 31, 27,
+    ],
+    [
+        19, 9, 4, 20, 28, 17, 8, 22, 29, 14, 25, 12, 24, 30, 16, 26, 31, 15, 7, 3, 1, 0, 18, 27,
+        13, 6, 21, 10, 23, 11, 5, 2,
+    ],
+    [
+        24, 4, 0, 14, 2, 7, 28, 23, 26, 6, 30, 20, 18, 25, 19, 3, 22, 11, 31, 21, 8, 27, 12, 9, 1,
+        29, 5, 15, 17, 10, 16, 13,
+    ],
+    [
+        27, 3, 21, 26, 17, 11, 20, 29, 19, 0, 12, 7, 13, 8, 31, 10, 5, 9, 14, 30, 18, 6, 28, 24, 2,
+        23, 16, 22, 4, 1, 25, 15,
+    ],
+];
+
+/// The fractional part of pi, the first 8 words are the initial state and the rest are the round constants.
+const PI: [u32; 136] = [
+    0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344, 0xa4093822, 0x299f31d0, 0x082efa98, 0xec4e6c89,
+    0x452821e6, 0x38d01377, 0xbe5466cf, 0x34e90c6c, 0xc0ac29b7, 0xc97c50dd, 0x3f84d5b5, 0xb5470917,
+    0x9216d5d9, 0x8979fb1b, 0xd1310ba6, 0x98dfb5ac, 0x2ffd72db, 0xd01adfb7, 0xb8e1afed, 0x6a267e96,
+    0xba7c9045, 0xf12c7f99, 0x24a19947, 0xb3916cf7, 0x0801f2e2, 0x858efc16, 0x636920d8, 0x71574e69,
+    0xa458fea3, 0xf4933d7e, 0x0d95748f, 0x728eb658, 0x718bcd58, 0x82154aee, 0x7b54a41d, 0xc25a59b5,
+    0x9c30d539, 0x2af26013, 0xc5d1b023, 0x286085f0, 0xca417918, 0xb8db38ef, 0x8e79dcb0, 0x603a180e,
+    0x6c9e0e8b, 0xb01e8a3e, 0xd71577c1, 0xbd314b27, 0x78af2fda, 0x55605c60, 0xe65525f3, 0xaa55ab94,
+    0x57489862, 0x63e81440, 0x55ca396a, 0x2aab10b6, 0xb4cc5c34, 0x1141e8ce, 0xa15486af, 0x7c72e993,
+    0xb3ee1411, 0x636fbc2a, 0x2ba9c55d, 0x741831f6, 0xce5c3e16, 0x9b87931e, 0xafd6ba33, 0x6c24cf5c,
+    0x7a325381, 0x28958677, 0x3b8f4898, 0x6b4bb9af, 0xc4bfe81b, 0x66282193, 0x61d809cc, 0xfb21a991,
+    0x487cac60, 0x5dec8032, 0xef845d5d, 0xe98575b1, 0xdc262302, 0xeb651b88, 0x23893e81, 0xd396acc5,
+    0x0f6d6ff3, 0x83f44239, 0x2e0b4482, 0xa4842004, 0x69c8f04a, 0x9e1f9b5e, 0x21c66842, 0xf6e96c9a,
+    0x670c9c61, 0xabd388f0, 0x6a51a0d2, 0xd8542f68, 0x960fa728, 0xab5133a3, 0x6eef0b6c, 0x137a3be4,
+    0xba3bf050, 0x7efb2a98, 0xa1f1651d, 0x39af0176, 0x66ca593e, 0x82430e88, 0x8cee8619, 0x456f9fb4,
+    0x7d84a5c3, 0x3b8b5ebe, 0xe06f75d8, 0x85c12073, 0x401a449f, 0x56c16aa6, 0x4ed3aa62, 0x363f7706,
+    0x1bfedf72, 0x429b023d, 0x37d0d724, 0xd00a1248, 0xdb0fead3, 0x49f1c09b, 0x075372c9, 0x80991b7b,
+    0x25d479d8, 0xf6e8def7, 0xe3fe501a, 0xb6794c3b, 0x976ce0bd, 0x04c006ba, 0xc1a94fb6, 0x409f60c4,
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::paranoid_hash::{
+        tester::{HasherTestWrapper, TestData},
+        Hasher,
+    };
+
+    use super::{
+        HAVAL_128_3, HAVAL_128_4, HAVAL_128_5, HAVAL_160_3, HAVAL_160_4, HAVAL_160_5, HAVAL_192_3,
+        HAVAL_192_4, HAVAL_192_5, HAVAL_224_3, HAVAL_224_4, HAVAL_224_5, HAVAL_256_3, HAVAL_256_4,
+        HAVAL_256_5,
+    };
+
+    const TEST1: &[u8] = "".as_bytes();
+    const TEST2: &[u8] = "a".as_bytes();
+    const TEST3: &[u8] = "abc".as_bytes();
+    const TEST4: &[u8] = "message digest".as_bytes();
+    const TEST5: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+    const TEST6: &[u8] =
+        "12345678901234567890123456789012345678901234567890123456789012345678901234567890"
+            .as_bytes();
+
+    const HAVAL_128_3_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "c68f39913f901f3ddf44c707357a7d70",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "0cd40739683e15f01ca5dbceef4059f1",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "9e40ed883fb63e985d299b40cda2b8f2",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "3caf4a79e81adcd6d1716bcc1cef4573",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "713502673d67e5fa557629a71d331945",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "d15c566b5c1b84928236ca065565208f",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "1af89651be50fb95df8543b9ec38ea0f",
+        },
+    ];
+
+    const HAVAL_160_3_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "d353c3ae22a25401d257643836d7231a9a95f953",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "4da08f514a7275dbc4cece4a347385983983a830",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "b21e876c4d391e2a897661149d83576b5530a089",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "43a47f6f1c016207f08be8115c0977bf155346da",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "b338ac397e8bccadcccd96549cadd4882d834107",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "34038b573ef4a91ebfe54fc67b8b24ea11654eac",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "f6b3e34ceb36233b8f237a7273475be80ca0ffa8",
+        },
+    ];
+
+    const HAVAL_192_3_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "e9c48d7903eaf2a91c5b350151efcb175c0fc82de2289a4e",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "b359c8835647f5697472431c142731ff6e2cddcacc4f6e08",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "a7b14c9ef3092319b0e75e3b20b957d180bf20745629e8de",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "6c4d9ec368efc96eeea58e132bdb2391c2b3e9d20190f7ea",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "58e6ced002e311172483d434ba738ad033e7fa950e431503",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "0706697dd426d8549504c64fb1de2eca5492513b18da7193",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "1ebec5ce869aa989e27e41460662f3d252c92c9c74a71f9e",
+        },
+    ];
+
+    const HAVAL_224_3_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "c5aae9d47bffcaaf84a8c6e7ccacd60a0dd1932be7b1a192b9214b6d",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "731814ba5605c59b673e4caae4ad28eeb515b3abc2b198336794e17b",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "5bc955220ba2346a948d2848eca37bdd5eca6ecca7b594bd32923fab",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "edf3e4add009ee89ee8ab03c39a3c749d20a48319c50c3a83861c540",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "e1d5792306f56b22419662b06d1885a66dca3eba01f53274c89aeaeb",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "0fe989b4633b10d7deffb1171d3134f9bead7a9dc5309f03fe2241cd",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "57a1cc2d243d08ec2ae4ae27cf1cbbdf57412a0e0256cee671d55af6",
+        },
+    ];
+
+    const HAVAL_256_3_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "4f6938531f0bc8991f62da7bbd6f7de3fad44562b8c6f4ebf146d5b4e46f7c17",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "47c838fbb4081d9525a0ff9b1e2c05a98f625714e72db289010374e27db021d8",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "8699f1e3384d05b2a84b032693e2b6f46df85a13a50d93808d6874bb8fb9e86c",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "911d1aad699e1b4a3a0e783bd5e68ba45392cc4915b1a17eca8a49da70879912",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "9446028f42b3768a41bd873ca69b0c006341d986613567f39eb61f96ca683300",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "29911201fc26e68ddfedd0fd5172c0af1f05b0a7ae2f027a04cbceb3be69f632",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "283a1cd61df7df0890b57228064ea8539955fcbffc4c8b7697ce8ce7b641c8af",
+        },
+    ];
+
+    const HAVAL_128_4_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "ee6bbf4d6a46a679b3a856c88538bb98",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "5cd07f03330c3b5020b29ba75911e17d",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "6f2132867c9648419adcd5013e532fa2",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "faee633871b30771ecda708d66fe6551",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "6eece560a2e8d6b919e81fe91b0e7156",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "edf66fa258f61206595b7c261cf2f812",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "a11098f2277ba23920be75aa41edb0d8",
+        },
+    ];
+
+    const HAVAL_160_4_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "1d33aae1be4146dbaaca0b6e70d7a11f10801525",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "e0a5be29627332034d4dd8a910a1a0e6fe04084d",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "77aca22f5b12cc09010afc9c0797308638b1cb9b",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "429346bb57211af6651060fd02db264fbe9c4365",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "6e739d01f5739ceed94da1a115b52d5951280560",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "238770cb2812aed0bd43335d5c8e5785e819a1f1",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "b1f6089274629b32ac35f785fb9441a38ad54943",
+        },
+    ];
+
+    const HAVAL_192_4_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "4a8372945afa55c7dead800311272523ca19d42ea47b72da",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "856c19f86214ea9a8a2f0c4b758b973cce72a2d8ff55505c",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "7e29881ed05c915903dd5e24a8e81cde5d910142ae66207c",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "e91960a06afbc8bd9f400a16135ed66e2745ef01d6d1cdf7",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "228ee09bc7e36151c6f285f558e6aede66ad38c8341592b9",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "c7c44bcc0f83946d6cba0ee6344ac0b7d80c38abce5d470f",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "64623e332b7bdf9750fa774377a42a173763550758eda022",
+        },
+    ];
+
+    const HAVAL_224_4_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "3e56243275b3b81561750550e36fcd676ad2f5dd9e15f2e89e6ed78e",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "742f1dbeeaf17f74960558b44f08aa98bdc7d967e6c0ab8f799b3ac1",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "124c43d2ba4884599d013e8c872bfea4c88b0b6bf6303974cbe04e68",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "86e52ecc72ccaec188c17033fafe8b652705fd6a7d9db2e0d10cab92",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "dddd6689885f6db4ad91e35a35e1f4498446510df798d4fd54b8654f",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "3a37ae6d1069343bb62e1ab5222c19c3d48da344d01583963210384a",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "ce3e8f706ed1ec30a17bfefe5bded47ce85cca4f8ad85d1cd13bd784",
+        },
+    ];
+
+    const HAVAL_256_4_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "c92b2e23091e80e375dadce26982482d197b1a2521be82da819f8ca2c579b99b",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "e686d2394a49b44d306ece295cf9021553221db132b36cc0ff5b593d39295899",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "8f409f1bb6b30c5016fdce55f652642261575bedca0b9533f32f5455459142b5",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "dbcc8e1011df45121d4ff2bb62c6c38949d76084f829c36d5929aee71b261f2f",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "c0d4c6ea514105fd1a9c38a238553fb7fa21d4127eb1a3035a75ce9d06a83d96",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "2187a29f8d4539010a3b91407304f74a0fc30325d91fd3c6bb585cbc45546543",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "8a4ae896c0f2bcdb5d22eab2d3840652c831dabc1280290a62555966a2c178a4",
+        },
+    ];
+
+    const HAVAL_128_5_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "184b8482a0c050dca54b59c7f05bf5dd",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "f23fbe704be8494bfa7a7fb4f8ab09e5",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "d054232fe874d9c6c6dc8e6a853519ea",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "c28052dc143c1c70450d3c0504756efe",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "696f02111f2e1da5c21d50eb782b7e8f",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "2395f65e5eeb485293fc561a0b349f1e",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "433e6705e237327eef1437d844d5c87f",
+        },
+    ];
+
+    const HAVAL_160_5_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "255158cfc1eed1a7be7c55ddd64d9790415b933b",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "f5147df7abc5e3c81b031268927c2b5761b5a2b5",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "ae646b04845e3351f00c5161d138940e1fa0c11c",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "2ac00ef52871b373eda407d7eafbd225987f33f1",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "ecce9fa8a428866304ff082af2f9062637d36b23",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "a807c7a8a1afa6d8dd64cb395917f5792748243c",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "77c078da0fd4f33b7d7dc5a52cf109f8b0b75c97",
+        },
+    ];
+
+    const HAVAL_192_5_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "4839d0626f95935e17ee2fc4509387bbe2cc46cb382ffe85",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "5ffa3b3548a6e2cfc06b7908ceb5263595df67cf9c4b9341",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "d12091104555b00119a8d07808a3380bf9e60018915b9025",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "8225efabaded623849843546cdf3c8c88e0fdca68f9a5a56",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "023d045f75d4bf051fd6e50f7b7417bf9949c4b5d2b4b7ef",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "0a60fce978d5777e5410c0b71b9053108265e3038f395b9f",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "b403eb17df6970528f7af2cd68b7a73f3ef6354408fe24cb",
+        },
+    ];
+
+    const HAVAL_224_5_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "4a0513c032754f5582a758d35917ac9adf3854219b39e3ac77d1837e",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "67b3cb8d4068e3641fa4f156e03b52978b421947328bfb9168c7655d",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "8081027a500147c512e5f1055986674d746d92af4841abeb89da64ad",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "877a7b891fce89036fc127756b07923ece3ba7c495922909cc89512e",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "03d953298c8e56b46385c6761cd4b2e377889a75c97eaea475421c73",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "55591d16852c9ef56bdac519790dfed8b0af1e719d531e20d65148da",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "93107cc348d9c66bee775d49551c28844082d31cd74449f604c0b388",
+        },
+    ];
+
+    const HAVAL_256_5_TESTS: &[TestData] = &[
+        TestData {
+            data: TEST1,
+            repeat: 1,
+            result: "be417bb4dd5cfb76c7126f4f8eeb1553a449039307b1a3cd451dbfdc0fbbe330",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1,
+            result: "de8fd5ee72a5e4265af0a756f4e1a1f65c9b2b2f47cf17ecf0d1b88679a3e22f",
+        },
+        TestData {
+            data: TEST3,
+            repeat: 1,
+            result: "976cd6254c337969e5913b158392a2921af16fca51f5601d486e0a9de01156e7",
+        },
+        TestData {
+            data: TEST4,
+            repeat: 1,
+            result: "7ccf22af7f99acd6ac84f176041329e2958fde1419a259d5a4b89d8f4115ad74",
+        },
+        TestData {
+            data: TEST5,
+            repeat: 1,
+            result: "b89c551cdfe2e06dbd4cea2be1bc7d557416c58ebb4d07cbc94e49f710c55be4",
+        },
+        TestData {
+            data: TEST6,
+            repeat: 1,
+            result: "68e57a72ad513af517469a96a0073ce212b42e772671687de3dfce4ff8cde9bf",
+        },
+        TestData {
+            data: TEST2,
+            repeat: 1000,
+            result: "895160426130860e829459269691913009542a6ac51752f154847a9359618f44",
+        },
+    ];
+
+    #[test]
+    fn tests() {
+        HasherTestWrapper::new(HAVAL_128_3::new()).run_tests(HAVAL_128_3_TESTS);
+
+        HasherTestWrapper::new(HAVAL_160_3::new()).run_tests(HAVAL_160_3_TESTS);
+
+        HasherTestWrapper::new(HAVAL_192_3::new()).run_tests(HAVAL_192_3_TESTS);
+
+        HasherTestWrapper::new(HAVAL_224_3::new()).run_tests(HAVAL_224_3_TESTS);
+
+        HasherTestWrapper::new(HAVAL_256_3::new()).run_tests(HAVAL_256_3_TESTS);
+
+        HasherTestWrapper::new(HAVAL_128_4::new()).run_tests(HAVAL_128_4_TESTS);
+
+        HasherTestWrapper::new(HAVAL_160_4::new()).run_tests(HAVAL_160_4_TESTS);
+
+        HasherTestWrapper::new(HAVAL_192_4::new()).run_tests(HAVAL_192_4_TESTS);
+
+        HasherTestWrapper::new(HAVAL_224_4::new()).run_tests(HAVAL_224_4_TESTS);
+
+        HasherTestWrapper::new(HAVAL_256_4::new()).run_tests(HAVAL_256_4_TESTS);
+
+        HasherTestWrapper::new(HAVAL_128_5::new()).run_tests(HAVAL_128_5_TESTS);
+
+        HasherTestWrapper::new(HAVAL_160_5::new()).run_tests(HAVAL_160_5_TESTS);
+
+        HasherTestWrapper::new(HAVAL_192_5::new()).run_tests(HAVAL_192_5_TESTS);
+
+        HasherTestWrapper::new(HAVAL_224_5::new()).run_tests(HAVAL_224_5_TESTS);
+
+        HasherTestWrapper::new(HAVAL_256_5::new()).run_tests(HAVAL_256_5_TESTS);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test1() {
+        let mut hasher = HAVAL_256_5::new();
+        hasher
+            .update("Not multiple of block size".as_bytes())
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test2() {
+        let mut hasher = HAVAL_128_3::new();
+        let data = [0u8; HAVAL_128_3::BLOCK_SIZE + 1];
+        hasher.update_last(&data).unwrap();
+    }
+}
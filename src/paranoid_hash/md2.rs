@@ -17,7 +17,7 @@ impl Hasher for MD2 {
             return Err(Error::UpdatingAfterFinished);
         }
 
-        if data.len() % Self::BLOCK_SIZE != 0 {
+        if !data.len().is_multiple_of(Self::BLOCK_SIZE) {
             return Err(Error::DataLengthMismatched(data.len(), Self::BLOCK_SIZE));
         }
 
@@ -83,6 +83,10 @@ impl Hasher for MD2 {
     fn digest_size(&self) -> usize {
         Self::DIGEST_SIZE
     }
+
+    impl_state!("MD2", checksum, buffer, count, is_done);
+
+    impl_box_clone!();
 }
 
 impl MD2 {
@@ -0,0 +1,3 @@
+use super::{MD4_BLOCK_SIZE, MD4_DIGEST_SIZE};
+
+create_rust_crypto_hasher!(MD4, md4, Md4, MD4_BLOCK_SIZE, MD4_DIGEST_SIZE);
@@ -1,8 +1,9 @@
-use std::mem::size_of;
+use core::mem::size_of;
 
+use super::{MD4_BLOCK_SIZE, MD4_DIGEST_SIZE};
 use crate::{paranoid_hash::Hasher, Error, Result};
 
-#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct MD4 {
     state: [u32; 4],
     count: u64,
@@ -38,11 +39,15 @@ impl Hasher for MD4 {
     fn digest_size(&self) -> usize {
         Self::DIGEST_SIZE
     }
+
+    impl_state!("MD4", state, count, is_done, digest);
+
+    impl_box_clone!();
 }
 
 impl MD4 {
-    pub const BLOCK_SIZE: usize = 64;
-    pub const DIGEST_SIZE: usize = 16;
+    pub const BLOCK_SIZE: usize = MD4_BLOCK_SIZE;
+    pub const DIGEST_SIZE: usize = MD4_DIGEST_SIZE;
 
     const U32_BLOCK_SIZE: usize = Self::BLOCK_SIZE / size_of::<u32>();
 
@@ -117,6 +122,12 @@ impl MD4 {
     }
 }
 
+impl Default for MD4 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[inline(always)]
 fn hash_1(x: u32, y: u32, z: u32) -> u32 {
     x & y | !x & z
@@ -170,75 +181,3 @@ const S31: u32 = 3;
 const S32: u32 = 9;
 const S33: u32 = 11;
 const S34: u32 = 15;
-
-#[cfg(test)]
-mod tests {
-    use crate::paranoid_hash::{
-        tester::{HasherTestWrapper, TestData},
-        Hasher,
-    };
-
-    use super::MD4;
-
-    const TESTS: &[TestData] = &[
-        TestData {
-            data: "".as_bytes(),
-            repeat: 1,
-            result: "31d6cfe0d16ae931b73c59d7e0c089c0",
-        },
-        TestData {
-            data: "a".as_bytes(),
-            repeat: 1,
-            result: "bde52cb31de33e46245e05fbdbd6fb24",
-        },
-        TestData {
-            data: "abc".as_bytes(),
-            repeat: 1,
-            result: "a448017aaf21d8525fc10ae87aa6729d",
-        },
-        TestData {
-            data: "message digest".as_bytes(),
-            repeat: 1,
-            result: "d9130a8164549fe818874806e1c7014b",
-        },
-        TestData {
-            data: "abcdefghijklmnopqrstuvwxyz".as_bytes(),
-            repeat: 1,
-            result: "d79e1c308aa5bbcdeea8ed63df412da9",
-        },
-        TestData {
-            data: "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789".as_bytes(),
-            repeat: 1,
-            result: "043f8582f241db351ce627e153e7f0e4",
-        },
-        TestData {
-            data:
-                "12345678901234567890123456789012345678901234567890123456789012345678901234567890"
-                    .as_bytes(),
-            repeat: 1,
-            result: "e33b4ddc9c38f2199c3e7b164fcc0536",
-        },
-    ];
-
-    #[test]
-    fn tests_from_rfc() {
-        HasherTestWrapper::new(MD4::new()).run_tests(TESTS);
-    }
-
-    #[test]
-    #[should_panic]
-    fn panic_test1() {
-        let mut hasher = MD4::new();
-        hasher
-            .update("Not multiple of block size".as_bytes())
-            .unwrap();
-    }
-
-    #[test]
-    #[should_panic]
-    fn panic_test2() {
-        let mut hasher = MD4::new();
-        let data = [0u8; MD4::BLOCK_SIZE + 1];
-        hasher.update_last(&data).unwrap();
-    }
-}
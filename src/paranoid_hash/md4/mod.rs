@@ -0,0 +1,88 @@
+const MD4_BLOCK_SIZE: usize = 64;
+const MD4_DIGEST_SIZE: usize = 16;
+
+#[cfg(feature = "alter-impl")]
+pub(crate) mod alter;
+pub(crate) mod mine;
+
+// The md4 crate keeps its chaining value and its compression function private, so its hasher
+// can't save and restore state, and mine stays the default one even with alter-impl.
+pub use self::mine::MD4;
+
+#[cfg(test)]
+mod tests {
+    use crate::paranoid_hash::{
+        tester::{HasherTestWrapper, TestData},
+        Hasher,
+    };
+
+    use super::MD4;
+
+    const TESTS: &[TestData] = &[
+        TestData {
+            data: "".as_bytes(),
+            repeat: 1,
+            result: "31d6cfe0d16ae931b73c59d7e0c089c0",
+        },
+        TestData {
+            data: "a".as_bytes(),
+            repeat: 1,
+            result: "bde52cb31de33e46245e05fbdbd6fb24",
+        },
+        TestData {
+            data: "abc".as_bytes(),
+            repeat: 1,
+            result: "a448017aaf21d8525fc10ae87aa6729d",
+        },
+        TestData {
+            data: "message digest".as_bytes(),
+            repeat: 1,
+            result: "d9130a8164549fe818874806e1c7014b",
+        },
+        TestData {
+            data: "abcdefghijklmnopqrstuvwxyz".as_bytes(),
+            repeat: 1,
+            result: "d79e1c308aa5bbcdeea8ed63df412da9",
+        },
+        TestData {
+            data: "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789".as_bytes(),
+            repeat: 1,
+            result: "043f8582f241db351ce627e153e7f0e4",
+        },
+        TestData {
+            data:
+                "12345678901234567890123456789012345678901234567890123456789012345678901234567890"
+                    .as_bytes(),
+            repeat: 1,
+            result: "e33b4ddc9c38f2199c3e7b164fcc0536",
+        },
+    ];
+
+    #[test]
+    fn tests_from_rfc() {
+        HasherTestWrapper::new(MD4::new()).run_tests(TESTS);
+    }
+
+    #[cfg(feature = "alter-impl")]
+    #[test]
+    fn alter_tests() {
+        HasherTestWrapper::new(super::alter::MD4::new()).run_tests(TESTS);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test1() {
+        let mut hasher = MD4::new();
+        hasher
+            .update("Not multiple of block size".as_bytes())
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test2() {
+        let mut hasher = MD4::new();
+        let data = [0u8; MD4::BLOCK_SIZE + 1];
+        hasher.update_last(&data).unwrap();
+    }
+}
@@ -0,0 +1,3 @@
+use super::{MD5_BLOCK_SIZE, MD5_DIGEST_SIZE};
+
+create_rust_crypto_hasher!(MD5, md5, Md5, MD5_BLOCK_SIZE, MD5_DIGEST_SIZE);
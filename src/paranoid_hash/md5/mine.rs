@@ -1,8 +1,12 @@
-use std::mem::size_of;
+use core::mem::size_of;
 
-use crate::{paranoid_hash::Hasher, Error, Result};
+use super::{MD5_BLOCK_SIZE, MD5_DIGEST_SIZE};
+use crate::{
+    paranoid_hash::{hash_helper::write_u32_const, Hasher},
+    Error, Result,
+};
 
-#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct MD5 {
     state: [u32; 4],
     count: u64,
@@ -38,11 +42,15 @@ impl Hasher for MD5 {
     fn digest_size(&self) -> usize {
         Self::DIGEST_SIZE
     }
+
+    impl_state!("MD5", state, count, is_done, digest);
+
+    impl_box_clone!();
 }
 
 impl MD5 {
-    pub const BLOCK_SIZE: usize = 64;
-    pub const DIGEST_SIZE: usize = 16;
+    pub const BLOCK_SIZE: usize = MD5_BLOCK_SIZE;
+    pub const DIGEST_SIZE: usize = MD5_DIGEST_SIZE;
 
     const U32_BLOCK_SIZE: usize = Self::BLOCK_SIZE / size_of::<u32>();
 
@@ -55,8 +63,21 @@ impl MD5 {
         }
     }
 
+    /// Hashes `data` in one go, usable in const contexts.
+    pub const fn hash_const(data: &[u8]) -> [u8; Self::DIGEST_SIZE] {
+        let hasher = md_hash_const!(
+            Self::new(),
+            data,
+            Self::BLOCK_SIZE,
+            read_u32_const,
+            8,
+            false
+        );
+        write_u32_const(&hasher.state, false)
+    }
+
     #[inline]
-    fn update_block(&mut self, block: &[u32; Self::U32_BLOCK_SIZE]) {
+    const fn update_block(&mut self, block: &[u32; Self::U32_BLOCK_SIZE]) {
         let [mut a, mut b, mut c, mut d] = self.state;
 
         a = round_1(a, b, c, d, block[0], S11, 0xd76aa478);
@@ -134,28 +155,34 @@ impl MD5 {
     }
 }
 
+impl Default for MD5 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[inline(always)]
-fn hash_1(x: u32, y: u32, z: u32) -> u32 {
+const fn hash_1(x: u32, y: u32, z: u32) -> u32 {
     x & y | !x & z
 }
 
 #[inline(always)]
-fn hash_2(x: u32, y: u32, z: u32) -> u32 {
+const fn hash_2(x: u32, y: u32, z: u32) -> u32 {
     x & z | y & !z
 }
 
 #[inline(always)]
-fn hash_3(x: u32, y: u32, z: u32) -> u32 {
+const fn hash_3(x: u32, y: u32, z: u32) -> u32 {
     x ^ y ^ z
 }
 
 #[inline(always)]
-fn hash_4(x: u32, y: u32, z: u32) -> u32 {
+const fn hash_4(x: u32, y: u32, z: u32) -> u32 {
     y ^ (x | !z)
 }
 
 #[inline(always)]
-fn round_1(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, ac: u32) -> u32 {
+const fn round_1(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, ac: u32) -> u32 {
     hash_1(b, c, d)
         .wrapping_add(x)
         .wrapping_add(ac)
@@ -165,7 +192,7 @@ fn round_1(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, ac: u32) -> u32 {
 }
 
 #[inline(always)]
-fn round_2(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, ac: u32) -> u32 {
+const fn round_2(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, ac: u32) -> u32 {
     hash_2(b, c, d)
         .wrapping_add(x)
         .wrapping_add(ac)
@@ -175,7 +202,7 @@ fn round_2(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, ac: u32) -> u32 {
 }
 
 #[inline(always)]
-fn round_3(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, ac: u32) -> u32 {
+const fn round_3(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, ac: u32) -> u32 {
     hash_3(b, c, d)
         .wrapping_add(x)
         .wrapping_add(ac)
@@ -185,7 +212,7 @@ fn round_3(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, ac: u32) -> u32 {
 }
 
 #[inline(always)]
-fn round_4(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, ac: u32) -> u32 {
+const fn round_4(a: u32, b: u32, c: u32, d: u32, x: u32, s: u32, ac: u32) -> u32 {
     hash_4(b, c, d)
         .wrapping_add(x)
         .wrapping_add(ac)
@@ -210,75 +237,3 @@ const S41: u32 = 6;
 const S42: u32 = 10;
 const S43: u32 = 15;
 const S44: u32 = 21;
-
-#[cfg(test)]
-mod tests {
-    use crate::paranoid_hash::{
-        tester::{HasherTestWrapper, TestData},
-        Hasher,
-    };
-
-    use super::MD5;
-
-    const TESTS: &[TestData] = &[
-        TestData {
-            data: "".as_bytes(),
-            repeat: 1,
-            result: "d41d8cd98f00b204e9800998ecf8427e",
-        },
-        TestData {
-            data: "a".as_bytes(),
-            repeat: 1,
-            result: "0cc175b9c0f1b6a831c399e269772661",
-        },
-        TestData {
-            data: "abc".as_bytes(),
-            repeat: 1,
-            result: "900150983cd24fb0d6963f7d28e17f72",
-        },
-        TestData {
-            data: "message digest".as_bytes(),
-            repeat: 1,
-            result: "f96b697d7cb7938d525a2f31aaf161d0",
-        },
-        TestData {
-            data: "abcdefghijklmnopqrstuvwxyz".as_bytes(),
-            repeat: 1,
-            result: "c3fcd3d76192e4007dfb496cca67e13b",
-        },
-        TestData {
-            data: "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789".as_bytes(),
-            repeat: 1,
-            result: "d174ab98d277d9f5a5611c2c9f419d9f",
-        },
-        TestData {
-            data:
-                "12345678901234567890123456789012345678901234567890123456789012345678901234567890"
-                    .as_bytes(),
-            repeat: 1,
-            result: "57edf4a22be3c955ac49da2e2107b67a",
-        },
-    ];
-
-    #[test]
-    fn tests_from_rfc() {
-        HasherTestWrapper::new(MD5::new()).run_tests(TESTS);
-    }
-
-    #[test]
-    #[should_panic]
-    fn panic_test1() {
-        let mut hasher = MD5::new();
-        hasher
-            .update("Not multiple of block size".as_bytes())
-            .unwrap();
-    }
-
-    #[test]
-    #[should_panic]
-    fn panic_test2() {
-        let mut hasher = MD5::new();
-        let data = [0u8; MD5::BLOCK_SIZE + 1];
-        hasher.update_last(&data).unwrap();
-    }
-}
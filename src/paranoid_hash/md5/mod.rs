@@ -0,0 +1,96 @@
+const MD5_BLOCK_SIZE: usize = 64;
+const MD5_DIGEST_SIZE: usize = 16;
+
+#[cfg(feature = "alter-impl")]
+pub(crate) mod alter;
+pub(crate) mod mine;
+
+// The md-5 crate keeps its chaining value and its compression function private, so its hasher
+// can't save and restore state, and mine stays the default one even with alter-impl.
+pub use self::mine::MD5;
+
+#[cfg(test)]
+mod tests {
+    use crate::paranoid_hash::{
+        tester::{run_const_tests, HasherTestWrapper, TestData},
+        Hasher,
+    };
+
+    use super::MD5;
+
+    const TESTS: &[TestData] = &[
+        TestData {
+            data: "".as_bytes(),
+            repeat: 1,
+            result: "d41d8cd98f00b204e9800998ecf8427e",
+        },
+        TestData {
+            data: "a".as_bytes(),
+            repeat: 1,
+            result: "0cc175b9c0f1b6a831c399e269772661",
+        },
+        TestData {
+            data: "abc".as_bytes(),
+            repeat: 1,
+            result: "900150983cd24fb0d6963f7d28e17f72",
+        },
+        TestData {
+            data: "message digest".as_bytes(),
+            repeat: 1,
+            result: "f96b697d7cb7938d525a2f31aaf161d0",
+        },
+        TestData {
+            data: "abcdefghijklmnopqrstuvwxyz".as_bytes(),
+            repeat: 1,
+            result: "c3fcd3d76192e4007dfb496cca67e13b",
+        },
+        TestData {
+            data: "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789".as_bytes(),
+            repeat: 1,
+            result: "d174ab98d277d9f5a5611c2c9f419d9f",
+        },
+        TestData {
+            data:
+                "12345678901234567890123456789012345678901234567890123456789012345678901234567890"
+                    .as_bytes(),
+            repeat: 1,
+            result: "57edf4a22be3c955ac49da2e2107b67a",
+        },
+    ];
+
+    #[test]
+    fn tests_from_rfc() {
+        HasherTestWrapper::new(MD5::new()).run_tests(TESTS);
+    }
+
+    #[cfg(feature = "alter-impl")]
+    #[test]
+    fn alter_tests() {
+        HasherTestWrapper::new(super::alter::MD5::new()).run_tests(TESTS);
+    }
+
+    #[test]
+    fn const_tests() {
+        const DIGEST: [u8; MD5::DIGEST_SIZE] = MD5::hash_const(b"abc");
+        assert_eq!(hex::encode(DIGEST), "900150983cd24fb0d6963f7d28e17f72");
+
+        run_const_tests(MD5::new(), MD5::hash_const);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test1() {
+        let mut hasher = MD5::new();
+        hasher
+            .update("Not multiple of block size".as_bytes())
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test2() {
+        let mut hasher = MD5::new();
+        let data = [0u8; MD5::BLOCK_SIZE + 1];
+        hasher.update_last(&data).unwrap();
+    }
+}
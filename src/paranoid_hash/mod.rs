@@ -1,28 +1,105 @@
+mod backend;
+mod buffered;
+pub mod cavp;
 mod common;
+#[cfg(feature = "crc32")]
 mod crc32;
+mod cross_check;
+#[cfg(feature = "digest")]
+mod digest_compat;
+mod digest_value;
+#[cfg(feature = "edonr")]
+mod edonr;
+#[cfg(feature = "fnv")]
+mod fnv;
+#[cfg(feature = "has160")]
+mod has160;
 mod hash_helper;
 mod hasher;
+mod hasher_tag;
+#[cfg(feature = "std")]
+mod hashing_io;
+#[cfg(feature = "haval")]
+mod haval;
+#[cfg(feature = "md2")]
 mod md2;
+#[cfg(feature = "md4")]
 mod md4;
+#[cfg(feature = "md5")]
 mod md5;
+mod multi;
+#[cfg(feature = "murmur3")]
+mod murmur3;
+mod oneshot;
+mod registry;
+mod self_test;
+#[cfg(feature = "sha1")]
 mod sha1;
+#[cfg(feature = "sha1dc")]
+mod sha1dc;
+#[cfg(feature = "sha2")]
 mod sha2;
+#[cfg(feature = "sha3")]
 mod sha3;
+#[cfg(feature = "snefru")]
+mod snefru;
+mod state;
 mod tester;
+mod verification;
 
+#[cfg(feature = "sha1")]
 pub use self::sha1::SHA1;
-pub use self::sha2::SHA2_224;
-pub use self::sha2::SHA2_256;
-pub use self::sha2::SHA2_384;
-pub use self::sha2::SHA2_512;
-pub use crc32::CRC32;
-pub use crc32::CRC32C;
+#[cfg(feature = "sha1dc")]
+pub use self::sha1dc::SHA1DC;
+#[cfg(feature = "sha2")]
+pub use self::sha2::{SHA2_224, SHA2_256, SHA2_384, SHA2_512};
+pub use backend::native;
+#[cfg(feature = "alter-impl")]
+pub use backend::rust_crypto;
+pub use backend::Backend;
+pub use buffered::BufferedHasher;
+#[cfg(feature = "crc32")]
+pub use crc32::{CRC32, CRC32C};
+pub use cross_check::CrossCheckHasher;
+#[cfg(feature = "digest")]
+pub use digest_compat::DigestHasher;
+pub use digest_value::verify_digest;
+pub use digest_value::Digest;
+#[cfg(feature = "edonr")]
+pub use edonr::{EDONR_256, EDONR_512};
+#[cfg(feature = "fnv")]
+pub use fnv::{FNV1A_128, FNV1A_32, FNV1A_64, FNV1_128, FNV1_32, FNV1_64};
+#[cfg(feature = "has160")]
+pub use has160::HAS160;
+pub use hasher::BitHasher;
 pub use hasher::Hasher;
-pub use hasher::HasherTag;
+pub use hasher_tag::HasherTag;
+pub use hasher_tag::SecurityStrength;
+#[cfg(feature = "std")]
+pub use hashing_io::{HashingReader, HashingWriter};
+#[cfg(feature = "haval")]
+pub use haval::{
+    HAVAL_128_3, HAVAL_128_4, HAVAL_128_5, HAVAL_160_3, HAVAL_160_4, HAVAL_160_5, HAVAL_192_3,
+    HAVAL_192_4, HAVAL_192_5, HAVAL_224_3, HAVAL_224_4, HAVAL_224_5, HAVAL_256_3, HAVAL_256_4,
+    HAVAL_256_5,
+};
+#[cfg(feature = "md2")]
 pub use md2::MD2;
+#[cfg(feature = "md4")]
 pub use md4::MD4;
+#[cfg(feature = "md5")]
 pub use md5::MD5;
-pub use sha3::SHA3_224;
-pub use sha3::SHA3_256;
-pub use sha3::SHA3_384;
-pub use sha3::SHA3_512;
+pub use multi::MultiHasher;
+#[cfg(feature = "murmur3")]
+pub use murmur3::{MURMUR3_X64_128, MURMUR3_X86_32};
+pub use oneshot::{hash, hash_many};
+#[cfg(feature = "std")]
+pub use oneshot::{hash_file, hash_file_many, hash_reader, hash_reader_many};
+pub use registry::HasherFactory;
+pub use registry::HasherRegistry;
+pub use self_test::self_test;
+#[cfg(feature = "sha3")]
+pub use sha3::{KeccakSponge, SHA3_224, SHA3_256, SHA3_384, SHA3_512};
+#[cfg(feature = "snefru")]
+pub use snefru::{SNEFRU_128, SNEFRU_256};
+pub use verification::Verification;
@@ -0,0 +1,206 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    paranoid_hash::{BufferedHasher, Hasher, HasherTag},
+    Result,
+};
+
+/// Feeds the same data to several hashers, e.g. to get the MD5 and SHA-256 of a blob in one pass.
+///
+/// Every hasher is buffered on its own, so `update` accepts slices of any length no matter the
+/// block sizes.
+#[derive(Debug)]
+pub struct MultiHasher<H: Hasher = Box<dyn Hasher + Send>> {
+    hashers: Vec<BufferedHasher<H>>,
+}
+
+impl MultiHasher {
+    /// Creates the built-in hashers of the tags.
+    pub fn from_tags(tags: &[HasherTag]) -> Self {
+        Self::with_hashers(tags.iter().map(|tag| tag.new_send_hasher()))
+    }
+}
+
+impl<H: Hasher> MultiHasher<H> {
+    pub fn new() -> Self {
+        Self {
+            hashers: Vec::new(),
+        }
+    }
+
+    pub fn with_hashers(hashers: impl IntoIterator<Item = H>) -> Self {
+        Self {
+            hashers: hashers.into_iter().map(BufferedHasher::new).collect(),
+        }
+    }
+
+    /// The hasher only sees the data from the next `update` on.
+    pub fn push(&mut self, hasher: H) {
+        self.hashers.push(BufferedHasher::new(hasher));
+    }
+
+    pub fn hashers(&self) -> &[BufferedHasher<H>] {
+        &self.hashers
+    }
+
+    pub fn len(&self) -> usize {
+        self.hashers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hashers.is_empty()
+    }
+
+    pub fn update(&mut self, data: &[u8]) -> Result<()> {
+        for hasher in self.hashers.iter_mut() {
+            hasher.update(data)?;
+        }
+
+        Ok(())
+    }
+
+    /// Finalizes all the hashers, the digests are in the same order as the hashers.
+    pub fn finalize(&mut self) -> Result<Vec<Vec<u8>>> {
+        self.hashers
+            .iter_mut()
+            .map(|h| h.finalize().map(|d| d.to_vec()))
+            .collect()
+    }
+
+    pub fn reset(&mut self) {
+        for hasher in self.hashers.iter_mut() {
+            hasher.reset();
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<H: Hasher + Send> MultiHasher<H> {
+    /// Same as `update`, but every hasher runs in its own scoped thread. Spawning the threads
+    /// costs more than hashing a few kilobytes, so pass large chunks.
+    pub fn update_parallel(&mut self, data: &[u8]) -> Result<()> {
+        if self.hashers.len() < 2 {
+            return self.update(data);
+        }
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .hashers
+                .iter_mut()
+                .map(|hasher| scope.spawn(move || hasher.update(data)))
+                .collect();
+
+            handles.into_iter().try_for_each(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            })
+        })
+    }
+}
+
+impl<H: Hasher> Default for MultiHasher<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    use alloc::{boxed::Box, vec::Vec};
+
+    #[cfg(feature = "md2")]
+    use crate::paranoid_hash::MD2;
+    #[cfg(feature = "sha3")]
+    use crate::paranoid_hash::SHA3_512;
+    use crate::{
+        paranoid_hash::{BufferedHasher, Hasher, HasherTag},
+        Error,
+    };
+
+    use super::MultiHasher;
+
+    const DATA: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+
+    fn expected(tags: &[HasherTag], data: &[u8]) -> Vec<Vec<u8>> {
+        tags.iter()
+            .map(|tag| {
+                let mut hasher = BufferedHasher::new(tag.new_hasher());
+                hasher.update(data).unwrap();
+                hasher.finalize().unwrap().to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn update_tests() {
+        let data = DATA.repeat(50);
+        let mut hasher = MultiHasher::from_tags(HasherTag::all());
+        assert_eq!(hasher.len(), HasherTag::all().len());
+        for chunk in data.chunks(13) {
+            hasher.update(chunk).unwrap();
+        }
+        assert_eq!(
+            hasher.finalize().unwrap(),
+            expected(HasherTag::all(), &data)
+        );
+        // nothing to finish without any algorithm family
+        if !hasher.is_empty() {
+            assert!(matches!(
+                hasher.update(DATA),
+                Err(Error::UpdatingAfterFinished)
+            ));
+        }
+
+        hasher.reset();
+        hasher.update(DATA).unwrap();
+        assert_eq!(hasher.finalize().unwrap(), expected(HasherTag::all(), DATA));
+    }
+
+    #[cfg(all(feature = "md2", feature = "sha3"))]
+    #[test]
+    fn generic_tests() {
+        let mut hasher: MultiHasher<Box<dyn Hasher>> = MultiHasher::new();
+        assert!(hasher.is_empty());
+        assert!(hasher.finalize().unwrap().is_empty());
+
+        hasher.push(Box::new(MD2::new()));
+        hasher.push(Box::new(SHA3_512::new()));
+        hasher.update(DATA).unwrap();
+        assert_eq!(
+            hasher.finalize().unwrap(),
+            expected(&[HasherTag::MD2, HasherTag::SHA3_512], DATA)
+        );
+
+        let mut hasher = MultiHasher::with_hashers([SHA3_512::new(), SHA3_512::new()]);
+        hasher.update(DATA).unwrap();
+        let digests = hasher.finalize().unwrap();
+        assert_eq!(digests[0], digests[1]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parallel_tests() {
+        let data = DATA.repeat(1000);
+        let mut hasher = MultiHasher::from_tags(HasherTag::all());
+        for chunk in data.chunks(10000) {
+            hasher.update_parallel(chunk).unwrap();
+        }
+        assert_eq!(
+            hasher.finalize().unwrap(),
+            expected(HasherTag::all(), &data)
+        );
+
+        #[cfg(feature = "sha1dc")]
+        {
+            let mut hasher = MultiHasher::from_tags(&[HasherTag::SHA1DC]);
+            hasher.update_parallel(DATA).unwrap();
+            assert_eq!(
+                hasher.finalize().unwrap(),
+                expected(&[HasherTag::SHA1DC], DATA)
+            );
+        }
+    }
+}
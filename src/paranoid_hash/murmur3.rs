@@ -0,0 +1,420 @@
+use crate::{
+    paranoid_hash::{hash_helper::slice_as_chunks, Hasher},
+    Error, Result,
+};
+
+/// MurmurHash3 x86_32, the digest is the 32-bit hash value in big endian.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MURMUR3_X86_32 {
+    seed: u32,
+    state: u32,
+    count: u32,
+    is_done: bool,
+    digest: [u8; Self::DIGEST_SIZE],
+}
+
+/// MurmurHash3 x64_128, the digest is h1 followed by h2, both in little endian,
+/// which is the byte order produced by the reference implementation.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MURMUR3_X64_128 {
+    seed: u32,
+    state: [u64; 2],
+    count: u64,
+    is_done: bool,
+    digest: [u8; Self::DIGEST_SIZE],
+}
+
+impl Hasher for MURMUR3_X86_32 {
+    fn update(&mut self, data: &[u8]) -> Result<()> {
+        if self.is_done {
+            return Err(Error::UpdatingAfterFinished);
+        }
+
+        if !data.len().is_multiple_of(Self::BLOCK_SIZE) {
+            return Err(Error::DataLengthMismatched(data.len(), Self::BLOCK_SIZE));
+        }
+
+        self.count = self.count.wrapping_add(data.len() as u32);
+
+        let block_chunks: &[[u8; Self::BLOCK_SIZE]] = slice_as_chunks(data);
+
+        for block_chunk in block_chunks {
+            self.update_block(u32::from_le_bytes(*block_chunk));
+        }
+
+        Ok(())
+    }
+
+    fn update_last(&mut self, data: &[u8]) -> Result<()> {
+        if self.is_done {
+            return Err(Error::UpdatingAfterFinished);
+        }
+
+        if data.len() > Self::BLOCK_SIZE {
+            return Err(Error::DataTooLarge(data.len(), Self::BLOCK_SIZE));
+        }
+
+        let seperator = data.len() / Self::BLOCK_SIZE * Self::BLOCK_SIZE;
+        self.update(&data[..seperator])?;
+
+        let tail = &data[seperator..];
+        self.count = self.count.wrapping_add(tail.len() as u32);
+
+        if !tail.is_empty() {
+            let mut block = [0u8; Self::BLOCK_SIZE];
+            block[..tail.len()].copy_from_slice(tail);
+            self.state ^= mix_k32(u32::from_le_bytes(block));
+        }
+
+        self.state ^= self.count;
+        self.digest = fmix32(self.state).to_be_bytes();
+
+        self.is_done = true;
+
+        Ok(())
+    }
+
+    fn digest(&self) -> Result<&[u8]> {
+        if !self.is_done {
+            return Err(Error::NotFinished);
+        }
+
+        Ok(&self.digest)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::with_seed(self.seed);
+    }
+
+    fn block_size(&self) -> usize {
+        Self::BLOCK_SIZE
+    }
+
+    fn digest_size(&self) -> usize {
+        Self::DIGEST_SIZE
+    }
+
+    impl_state!("MURMUR3_X86_32", seed, state, count, is_done, digest);
+
+    impl_box_clone!();
+}
+
+impl MURMUR3_X86_32 {
+    pub const BLOCK_SIZE: usize = 4;
+    pub const DIGEST_SIZE: usize = 4;
+
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    pub const fn with_seed(seed: u32) -> Self {
+        Self {
+            seed,
+            state: seed,
+            count: 0,
+            is_done: false,
+            digest: [0; Self::DIGEST_SIZE],
+        }
+    }
+
+    #[inline]
+    fn update_block(&mut self, k: u32) {
+        self.state ^= mix_k32(k);
+        self.state = self
+            .state
+            .rotate_left(13)
+            .wrapping_mul(5)
+            .wrapping_add(0xe6546b64);
+    }
+}
+
+impl Hasher for MURMUR3_X64_128 {
+    fn update(&mut self, data: &[u8]) -> Result<()> {
+        if self.is_done {
+            return Err(Error::UpdatingAfterFinished);
+        }
+
+        if !data.len().is_multiple_of(Self::BLOCK_SIZE) {
+            return Err(Error::DataLengthMismatched(data.len(), Self::BLOCK_SIZE));
+        }
+
+        self.count = self.count.wrapping_add(data.len() as u64);
+
+        let block_chunks: &[[u8; Self::BLOCK_SIZE]] = slice_as_chunks(data);
+
+        for block_chunk in block_chunks {
+            let [k1, k2]: &[[u8; 8]; 2] = slice_as_chunks(block_chunk).try_into().unwrap();
+            self.update_block(u64::from_le_bytes(*k1), u64::from_le_bytes(*k2));
+        }
+
+        Ok(())
+    }
+
+    fn update_last(&mut self, data: &[u8]) -> Result<()> {
+        if self.is_done {
+            return Err(Error::UpdatingAfterFinished);
+        }
+
+        if data.len() > Self::BLOCK_SIZE {
+            return Err(Error::DataTooLarge(data.len(), Self::BLOCK_SIZE));
+        }
+
+        let seperator = data.len() / Self::BLOCK_SIZE * Self::BLOCK_SIZE;
+        self.update(&data[..seperator])?;
+
+        let tail = &data[seperator..];
+        self.count = self.count.wrapping_add(tail.len() as u64);
+
+        let [mut h1, mut h2] = self.state;
+
+        if !tail.is_empty() {
+            let mut block = [0u8; Self::BLOCK_SIZE];
+            block[..tail.len()].copy_from_slice(tail);
+            let [k1, k2]: &[[u8; 8]; 2] = slice_as_chunks(&block).try_into().unwrap();
+            h1 ^= mix_k1_64(u64::from_le_bytes(*k1));
+            h2 ^= mix_k2_64(u64::from_le_bytes(*k2));
+        }
+
+        h1 ^= self.count;
+        h2 ^= self.count;
+
+        h1 = h1.wrapping_add(h2);
+        h2 = h2.wrapping_add(h1);
+
+        h1 = fmix64(h1);
+        h2 = fmix64(h2);
+
+        h1 = h1.wrapping_add(h2);
+        h2 = h2.wrapping_add(h1);
+
+        self.digest[..8].copy_from_slice(&h1.to_le_bytes());
+        self.digest[8..].copy_from_slice(&h2.to_le_bytes());
+
+        self.is_done = true;
+
+        Ok(())
+    }
+
+    fn digest(&self) -> Result<&[u8]> {
+        if !self.is_done {
+            return Err(Error::NotFinished);
+        }
+
+        Ok(&self.digest)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::with_seed(self.seed);
+    }
+
+    fn block_size(&self) -> usize {
+        Self::BLOCK_SIZE
+    }
+
+    fn digest_size(&self) -> usize {
+        Self::DIGEST_SIZE
+    }
+
+    impl_state!("MURMUR3_X64_128", seed, state, count, is_done, digest);
+
+    impl_box_clone!();
+}
+
+impl MURMUR3_X64_128 {
+    pub const BLOCK_SIZE: usize = 16;
+    pub const DIGEST_SIZE: usize = 16;
+
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    pub const fn with_seed(seed: u32) -> Self {
+        Self {
+            seed,
+            state: [seed as u64, seed as u64],
+            count: 0,
+            is_done: false,
+            digest: [0; Self::DIGEST_SIZE],
+        }
+    }
+
+    #[inline]
+    fn update_block(&mut self, k1: u64, k2: u64) {
+        let [mut h1, mut h2] = self.state;
+
+        h1 ^= mix_k1_64(k1);
+        h1 = h1
+            .rotate_left(27)
+            .wrapping_add(h2)
+            .wrapping_mul(5)
+            .wrapping_add(0x52dce729);
+
+        h2 ^= mix_k2_64(k2);
+        h2 = h2
+            .rotate_left(31)
+            .wrapping_add(h1)
+            .wrapping_mul(5)
+            .wrapping_add(0x38495ab5);
+
+        self.state = [h1, h2];
+    }
+}
+
+#[inline(always)]
+fn mix_k32(k: u32) -> u32 {
+    k.wrapping_mul(C1_32).rotate_left(15).wrapping_mul(C2_32)
+}
+
+#[inline(always)]
+fn mix_k1_64(k: u64) -> u64 {
+    k.wrapping_mul(C1_64).rotate_left(31).wrapping_mul(C2_64)
+}
+
+#[inline(always)]
+fn mix_k2_64(k: u64) -> u64 {
+    k.wrapping_mul(C2_64).rotate_left(33).wrapping_mul(C1_64)
+}
+
+#[inline(always)]
+fn fmix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^= h >> 16;
+    h
+}
+
+#[inline(always)]
+fn fmix64(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51afd7ed558ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ceb9fe1a85ec53);
+    h ^= h >> 33;
+    h
+}
+
+const C1_32: u32 = 0xcc9e2d51;
+const C2_32: u32 = 0x1b873593;
+const C1_64: u64 = 0x87c37b91114253d5;
+const C2_64: u64 = 0x4cf5ad432745937f;
+
+#[cfg(test)]
+mod tests {
+    use crate::paranoid_hash::{
+        tester::{HasherTestWrapper, TestData},
+        Hasher,
+    };
+
+    use super::{MURMUR3_X64_128, MURMUR3_X86_32};
+
+    const TEST_FOX: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+    const TEST_DIGITS: &[u8] =
+        "12345678901234567890123456789012345678901234567890123456789012345678901234567890"
+            .as_bytes();
+
+    const MURMUR3_X86_32_TESTS: &[TestData] = &[
+        TestData {
+            data: "".as_bytes(),
+            repeat: 1,
+            result: "00000000",
+        },
+        TestData {
+            data: "a".as_bytes(),
+            repeat: 1,
+            result: "3c2569b2",
+        },
+        TestData {
+            data: "abc".as_bytes(),
+            repeat: 1,
+            result: "b3dd93fa",
+        },
+        TestData {
+            data: "hello".as_bytes(),
+            repeat: 1,
+            result: "248bfa47",
+        },
+        TestData {
+            data: TEST_FOX,
+            repeat: 1,
+            result: "2e4ff723",
+        },
+        TestData {
+            data: TEST_DIGITS,
+            repeat: 1,
+            result: "f8e05287",
+        },
+    ];
+
+    const MURMUR3_X64_128_TESTS: &[TestData] = &[
+        TestData {
+            data: "".as_bytes(),
+            repeat: 1,
+            result: "00000000000000000000000000000000",
+        },
+        TestData {
+            data: "a".as_bytes(),
+            repeat: 1,
+            result: "897859f6655555855a890e51483ab5e6",
+        },
+        TestData {
+            data: "abc".as_bytes(),
+            repeat: 1,
+            result: "6778ad3f3f3f96b4522dca264174a23b",
+        },
+        TestData {
+            data: "hello".as_bytes(),
+            repeat: 1,
+            result: "029bbd41b3a7d8cb191dae486a901e5b",
+        },
+        TestData {
+            data: TEST_FOX,
+            repeat: 1,
+            result: "6c1b07bc7bbc4be347939ac4a93c437a",
+        },
+        TestData {
+            data: TEST_DIGITS,
+            repeat: 1,
+            result: "ee6a87a47f066391abf5d5a227ca4f77",
+        },
+    ];
+
+    #[test]
+    fn tests() {
+        HasherTestWrapper::new(MURMUR3_X86_32::new()).run_tests(MURMUR3_X86_32_TESTS);
+
+        HasherTestWrapper::new(MURMUR3_X64_128::new()).run_tests(MURMUR3_X64_128_TESTS);
+    }
+
+    #[test]
+    fn seeded_test() {
+        let mut hasher = HasherTestWrapper::new(MURMUR3_X86_32::with_seed(0x9747b28c));
+        hasher.update("hello".as_bytes());
+        assert_eq!(hex::encode(hasher.finalize()), "5d7f56e8");
+
+        // reset should keep the seed
+        hasher.reset();
+        hasher.update("hello".as_bytes());
+        assert_eq!(hex::encode(hasher.finalize()), "5d7f56e8");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test1() {
+        let mut hasher = MURMUR3_X64_128::new();
+        hasher
+            .update("Not multiple of block size".as_bytes())
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test2() {
+        let mut hasher = MURMUR3_X86_32::new();
+        let data = [0u8; MURMUR3_X86_32::BLOCK_SIZE + 1];
+        hasher.update_last(&data).unwrap();
+    }
+}
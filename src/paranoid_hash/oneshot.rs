@@ -0,0 +1,158 @@
+//! Hashes a whole buffer, reader or file at once with the built-in hashers.
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::{
+    fs::File,
+    io::{ErrorKind, Read},
+    path::Path,
+};
+
+#[cfg(feature = "std")]
+use crate::paranoid_hash::MultiHasher;
+use crate::{
+    paranoid_hash::{Digest, Hasher, HasherTag},
+    Result,
+};
+
+#[cfg(feature = "std")]
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+pub fn hash(tag: HasherTag, data: &[u8]) -> Result<Digest> {
+    let mut hasher = tag.new_hasher();
+    let length = data.len() / hasher.block_size() * hasher.block_size();
+    hasher.update(&data[..length])?;
+    hasher.update_last(&data[length..])?;
+    Digest::new(hasher.digest()?)
+}
+
+/// Digests are in the same order as the tags.
+pub fn hash_many(tags: &[HasherTag], data: &[u8]) -> Result<Vec<Digest>> {
+    tags.iter().map(|tag| hash(*tag, data)).collect()
+}
+
+#[cfg(feature = "std")]
+pub fn hash_reader(tag: HasherTag, reader: impl Read) -> Result<Digest> {
+    Ok(hash_reader_many(&[tag], reader)?[0])
+}
+
+/// Reads until the end once for all the tags, the digests are in the same order as the tags.
+#[cfg(feature = "std")]
+pub fn hash_reader_many(tags: &[HasherTag], mut reader: impl Read) -> Result<Vec<Digest>> {
+    let mut hasher = MultiHasher::from_tags(tags);
+
+    let mut buffer = alloc::vec![0u8; READ_BUFFER_SIZE];
+    loop {
+        let length = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(length) => length,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        hasher.update(&buffer[..length])?;
+    }
+
+    hasher
+        .finalize()?
+        .iter()
+        .map(|digest| Digest::new(digest))
+        .collect()
+}
+
+#[cfg(feature = "std")]
+pub fn hash_file(tag: HasherTag, path: impl AsRef<Path>) -> Result<Digest> {
+    hash_reader(tag, File::open(path)?)
+}
+
+/// Reads the file once for all the tags, the digests are in the same order as the tags.
+#[cfg(feature = "std")]
+pub fn hash_file_many(tags: &[HasherTag], path: impl AsRef<Path>) -> Result<Vec<Digest>> {
+    hash_reader_many(tags, File::open(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    use crate::paranoid_hash::{BufferedHasher, HasherTag};
+
+    use super::{hash, hash_many};
+
+    const DATA: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+    const MD5_RESULT: &str = "9e107d9d372bb6826bd81d3542a419d6";
+    const SHA1_RESULT: &str = "2fd4e1c67a2d28fced849ee1bb76e7391b93eb12";
+
+    #[test]
+    fn data_tests() {
+        #[cfg(feature = "md5")]
+        assert_eq!(hash(HasherTag::MD5, DATA).unwrap().to_hex(), MD5_RESULT);
+        #[cfg(feature = "sha1")]
+        assert_eq!(hash(HasherTag::SHA1, DATA).unwrap().to_hex(), SHA1_RESULT);
+        #[cfg(feature = "sha2")]
+        assert_eq!(
+            hash(HasherTag::SHA2_256, &[]).unwrap().to_hex(),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+
+        let data = DATA.repeat(100);
+        let digests = hash_many(HasherTag::all(), &data).unwrap();
+        assert_eq!(digests.len(), HasherTag::all().len());
+        for (tag, digest) in HasherTag::all().iter().zip(&digests) {
+            let mut hasher = BufferedHasher::new(tag.new_hasher());
+            for chunk in data.chunks(7) {
+                hasher.update(chunk).unwrap();
+            }
+            assert_eq!(digest.as_bytes(), hasher.finalize().unwrap());
+        }
+
+        assert!(hash_many(&[], DATA).unwrap().is_empty());
+    }
+
+    #[cfg(all(feature = "std", feature = "md5", feature = "sha1", feature = "sha3"))]
+    #[test]
+    fn reader_tests() {
+        use super::{hash_reader, hash_reader_many};
+
+        let digests = hash_reader_many(&[HasherTag::MD5, HasherTag::SHA1], DATA).unwrap();
+        assert_eq!(digests[0].to_hex(), MD5_RESULT);
+        assert_eq!(digests[1].to_hex(), SHA1_RESULT);
+
+        // longer than the read buffer
+        let data = DATA.repeat(10000);
+        assert_eq!(
+            hash_reader(HasherTag::SHA3_256, data.as_slice()).unwrap(),
+            hash(HasherTag::SHA3_256, &data).unwrap()
+        );
+    }
+
+    #[cfg(all(
+        feature = "std",
+        feature = "crc32",
+        feature = "md4",
+        feature = "md5",
+        feature = "sha2"
+    ))]
+    #[test]
+    fn file_tests() {
+        use super::{hash_file, hash_file_many};
+        use crate::Error;
+
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+        let data = std::fs::read(path).unwrap();
+        let tags = [HasherTag::CRC32, HasherTag::SHA2_512];
+        assert_eq!(
+            hash_file_many(&tags, path).unwrap(),
+            hash_many(&tags, &data).unwrap()
+        );
+        assert_eq!(
+            hash_file(HasherTag::MD4, path).unwrap(),
+            hash(HasherTag::MD4, &data).unwrap()
+        );
+
+        assert!(matches!(
+            hash_file(HasherTag::MD5, "does-not-exist"),
+            Err(Error::IoError(_))
+        ));
+    }
+}
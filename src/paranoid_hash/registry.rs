@@ -0,0 +1,199 @@
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+};
+use core::fmt;
+
+use crate::{
+    paranoid_hash::{hasher_tag::normalize_name, Hasher, HasherTag},
+    Error, Result,
+};
+
+pub type HasherFactory = Arc<dyn Fn() -> Box<dyn Hasher> + Send + Sync>;
+
+/// Creates hashers by name.
+///
+/// Names are matched the same way as `HasherTag` parses them, so "SHA-256" and "sha256" are the
+/// same key. `HasherRegistry::default()` contains the built-in hashers under their names and
+/// aliases.
+#[derive(Clone)]
+pub struct HasherRegistry {
+    // registered name, factory, and the algorithm if it was registered with a tag
+    factories: BTreeMap<String, (String, HasherFactory, Option<HasherTag>)>,
+}
+
+impl HasherRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            factories: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        for tag in HasherTag::all().iter().copied() {
+            registry.register_tag(tag, move || tag.new_hasher());
+        }
+        registry
+    }
+
+    /// Registers a factory under the name, an existing one with the same name is replaced and
+    /// returned.
+    pub fn register<F>(&mut self, name: &str, factory: F) -> Option<HasherFactory>
+    where
+        F: Fn() -> Box<dyn Hasher> + Send + Sync + 'static,
+    {
+        self.insert(name, Arc::new(factory), None)
+    }
+
+    /// Registers a factory under the name and the aliases of the tag, the hashers it creates are
+    /// known to be of the tag's algorithm, see `algorithm`.
+    pub fn register_tag<F>(&mut self, tag: HasherTag, factory: F)
+    where
+        F: Fn() -> Box<dyn Hasher> + Send + Sync + 'static,
+    {
+        let factory: HasherFactory = Arc::new(factory);
+        self.insert(tag.name(), Arc::clone(&factory), Some(tag));
+        for alias in tag.aliases() {
+            self.insert(alias, Arc::clone(&factory), Some(tag));
+        }
+    }
+
+    fn insert(
+        &mut self,
+        name: &str,
+        factory: HasherFactory,
+        algorithm: Option<HasherTag>,
+    ) -> Option<HasherFactory> {
+        self.factories
+            .insert(normalize_name(name), (name.to_string(), factory, algorithm))
+            .map(|(_, f, _)| f)
+    }
+
+    pub fn unregister(&mut self, name: &str) -> Option<HasherFactory> {
+        self.factories
+            .remove(&normalize_name(name))
+            .map(|(_, f, _)| f)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(&normalize_name(name))
+    }
+
+    pub fn create(&self, name: &str) -> Result<Box<dyn Hasher>> {
+        self.factories
+            .get(&normalize_name(name))
+            .map(|(_, factory, _)| factory())
+            .ok_or_else(|| Error::UnknownAlgorithm(name.to_string()))
+    }
+
+    /// Algorithm of the hashers registered under the name, only known for the ones registered
+    /// with `register_tag`, which includes the built-ins unless they were replaced.
+    pub fn algorithm(&self, name: &str) -> Option<HasherTag> {
+        self.factories
+            .get(&normalize_name(name))
+            .and_then(|(_, _, algorithm)| *algorithm)
+    }
+
+    pub fn create_from_tag(&self, tag: HasherTag) -> Result<Box<dyn Hasher>> {
+        self.create(tag.name())
+    }
+
+    /// Names as they were registered, in no particular order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.factories.values().map(|(name, _, _)| name.as_str())
+    }
+}
+
+impl Default for HasherRegistry {
+    fn default() -> Self {
+        Self::with_builtins()
+    }
+}
+
+impl fmt::Debug for HasherRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.names()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    #[cfg(feature = "md5")]
+    use crate::paranoid_hash::MD5;
+    #[cfg(feature = "sha2")]
+    use crate::paranoid_hash::SHA2_256;
+    use crate::{
+        paranoid_hash::{Hasher, HasherTag},
+        Error,
+    };
+
+    use super::HasherRegistry;
+
+    fn hash(registry: &HasherRegistry, name: &str) -> String {
+        let mut hasher = registry.create(name).unwrap();
+        hasher.update_last("abc".as_bytes()).unwrap();
+        hex::encode(hasher.digest().unwrap())
+    }
+
+    #[test]
+    fn builtin_tests() {
+        let registry = HasherRegistry::default();
+        for tag in HasherTag::all() {
+            assert!(registry.contains(tag.name()));
+            assert_eq!(
+                registry.create_from_tag(*tag).unwrap().digest_size(),
+                tag.digest_size()
+            );
+        }
+
+        #[cfg(feature = "sha2")]
+        for name in ["sha256", "SHA-256", "SHA2_256"] {
+            assert_eq!(
+                hash(&registry, name),
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+            );
+        }
+
+        assert!(matches!(
+            registry.create("sha3"),
+            Err(Error::UnknownAlgorithm(_))
+        ));
+        assert!(HasherRegistry::new().create("sha256").is_err());
+    }
+
+    #[cfg(all(feature = "md5", feature = "sha2"))]
+    #[test]
+    fn register_tests() {
+        let mut registry = HasherRegistry::default();
+
+        assert!(registry
+            .register("my-hash", || Box::new(MD5::new()))
+            .is_none());
+        assert_eq!(
+            hash(&registry, "My_Hash"),
+            "900150983cd24fb0d6963f7d28e17f72"
+        );
+        assert!(registry.names().any(|n| n == "my-hash"));
+
+        // built-ins can be replaced
+        assert!(registry
+            .register("md5", || Box::new(SHA2_256::new()))
+            .is_some());
+        assert_eq!(hash(&registry, "md5").len(), 64);
+
+        assert_eq!(registry.algorithm("SHA-256"), Some(HasherTag::SHA2_256));
+        assert_eq!(registry.algorithm("my-hash"), None);
+        assert_eq!(registry.algorithm("md5"), None);
+        registry.register_tag(HasherTag::MD5, || Box::new(MD5::new()));
+        assert_eq!(registry.algorithm("md5"), Some(HasherTag::MD5));
+
+        assert!(registry.unregister("MY-HASH").is_some());
+        assert!(!registry.contains("my-hash"));
+    }
+}
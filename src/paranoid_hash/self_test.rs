@@ -0,0 +1,149 @@
+use crate::{
+    paranoid_hash::{Backend, Digest, HasherTag},
+    Error, Result,
+};
+
+// Digests of "abc"
+const KNOWN_ANSWERS: &[(HasherTag, &str)] = &[
+    #[cfg(feature = "crc32")]
+    (HasherTag::CRC32, "352441c2"),
+    #[cfg(feature = "crc32")]
+    (HasherTag::CRC32C, "364b3fb7"),
+    #[cfg(feature = "md2")]
+    (HasherTag::MD2, "da853b0d3f88d99b30283a69e6ded6bb"),
+    #[cfg(feature = "md4")]
+    (HasherTag::MD4, "a448017aaf21d8525fc10ae87aa6729d"),
+    #[cfg(feature = "md5")]
+    (HasherTag::MD5, "900150983cd24fb0d6963f7d28e17f72"),
+    #[cfg(feature = "sha1")]
+    (HasherTag::SHA1, "a9993e364706816aba3e25717850c26c9cd0d89d"),
+    #[cfg(feature = "sha1dc")]
+    (HasherTag::SHA1DC, "a9993e364706816aba3e25717850c26c9cd0d89d"),
+    #[cfg(feature = "sha2")]
+    (HasherTag::SHA2_224, "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7"),
+    #[cfg(feature = "sha2")]
+    (HasherTag::SHA2_256, "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+    #[cfg(feature = "sha2")]
+    (HasherTag::SHA2_384, "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"),
+    #[cfg(feature = "sha2")]
+    (HasherTag::SHA2_512, "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"),
+    #[cfg(feature = "sha3")]
+    (HasherTag::SHA3_224, "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf"),
+    #[cfg(feature = "sha3")]
+    (HasherTag::SHA3_256, "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"),
+    #[cfg(feature = "sha3")]
+    (HasherTag::SHA3_384, "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25"),
+    #[cfg(feature = "sha3")]
+    (HasherTag::SHA3_512, "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"),
+    #[cfg(feature = "murmur3")]
+    (HasherTag::MURMUR3_X86_32, "b3dd93fa"),
+    #[cfg(feature = "murmur3")]
+    (HasherTag::MURMUR3_X64_128, "6778ad3f3f3f96b4522dca264174a23b"),
+    #[cfg(feature = "fnv")]
+    (HasherTag::FNV1_32, "439c2f4b"),
+    #[cfg(feature = "fnv")]
+    (HasherTag::FNV1A_32, "1a47e90b"),
+    #[cfg(feature = "fnv")]
+    (HasherTag::FNV1_64, "d8dcca186bafadcb"),
+    #[cfg(feature = "fnv")]
+    (HasherTag::FNV1A_64, "e71fa2190541574b"),
+    #[cfg(feature = "fnv")]
+    (HasherTag::FNV1_128, "a68bb2a4348b5822836dbc78c6aee73b"),
+    #[cfg(feature = "fnv")]
+    (HasherTag::FNV1A_128, "a68d622cec8b5822836dbc7977af7f3b"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_128_3, "9e40ed883fb63e985d299b40cda2b8f2"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_160_3, "b21e876c4d391e2a897661149d83576b5530a089"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_192_3, "a7b14c9ef3092319b0e75e3b20b957d180bf20745629e8de"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_224_3, "5bc955220ba2346a948d2848eca37bdd5eca6ecca7b594bd32923fab"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_256_3, "8699f1e3384d05b2a84b032693e2b6f46df85a13a50d93808d6874bb8fb9e86c"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_128_4, "6f2132867c9648419adcd5013e532fa2"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_160_4, "77aca22f5b12cc09010afc9c0797308638b1cb9b"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_192_4, "7e29881ed05c915903dd5e24a8e81cde5d910142ae66207c"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_224_4, "124c43d2ba4884599d013e8c872bfea4c88b0b6bf6303974cbe04e68"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_256_4, "8f409f1bb6b30c5016fdce55f652642261575bedca0b9533f32f5455459142b5"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_128_5, "d054232fe874d9c6c6dc8e6a853519ea"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_160_5, "ae646b04845e3351f00c5161d138940e1fa0c11c"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_192_5, "d12091104555b00119a8d07808a3380bf9e60018915b9025"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_224_5, "8081027a500147c512e5f1055986674d746d92af4841abeb89da64ad"),
+    #[cfg(feature = "haval")]
+    (HasherTag::HAVAL_256_5, "976cd6254c337969e5913b158392a2921af16fca51f5601d486e0a9de01156e7"),
+    #[cfg(feature = "snefru")]
+    (HasherTag::SNEFRU_128, "553d0648928299a0f22a275a02c83b10"),
+    #[cfg(feature = "snefru")]
+    (HasherTag::SNEFRU_256, "7d033205647a2af3dc8339f6cb25643c33ebc622d32979c4b612b02c4903031b"),
+    #[cfg(feature = "has160")]
+    (HasherTag::HAS160, "975e810488cf2a3d49838478124afce4b1c78804"),
+    #[cfg(feature = "edonr")]
+    (HasherTag::EDONR_256, "0360f65d97c2152ea6ebe3d462bf49831e2d5f67b6140992320585d89fd271ce"),
+    #[cfg(feature = "edonr")]
+    (HasherTag::EDONR_512, "fe79bcfa310245d9139da8bc91b99fd022326f7f3aca1dfdfb6c84e4125d71fe9bb6a1d41afce358f8472835220a7829d5146b2bbfc8e5c2627f60a9b517c1a4"),
+];
+
+/// Runs a known-answer test for every algorithm in every available backend.
+///
+/// Fails with `Error::SelfTestFailed` on the first wrong digest. Also run by
+/// `Builder::build` if `self_test` is enabled on the builder.
+pub fn self_test() -> Result<()> {
+    for (tag, expected) in KNOWN_ANSWERS.iter().copied() {
+        for backend in [Backend::Native, Backend::RustCrypto] {
+            match known_answer_test(tag, backend, expected) {
+                Err(Error::BackendUnavailable(..)) => continue,
+                result => result?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn known_answer_test(tag: HasherTag, backend: Backend, expected: &str) -> Result<()> {
+    let expected: Digest = expected.parse()?;
+    let mut hasher = tag.new_hasher_with(backend)?;
+
+    // a digest that can't be computed fails the test as well
+    match hasher.update_last("abc".as_bytes()).and(hasher.digest()) {
+        Ok(digest) if expected == digest => Ok(()),
+        _ => Err(Error::SelfTestFailed(tag, backend)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    use crate::{
+        paranoid_hash::{Backend, HasherTag},
+        Error,
+    };
+
+    use super::{known_answer_test, self_test, KNOWN_ANSWERS};
+
+    #[test]
+    fn self_tests() {
+        self_test().unwrap();
+
+        for tag in HasherTag::all() {
+            assert!(KNOWN_ANSWERS.iter().any(|(t, _)| t == tag), "{tag}");
+        }
+
+        #[cfg(feature = "md5")]
+        assert!(matches!(
+            known_answer_test(HasherTag::MD5, Backend::Native, "00"),
+            Err(Error::SelfTestFailed(HasherTag::MD5, Backend::Native))
+        ));
+    }
+}
@@ -1,12 +1,25 @@
-use sha1::Digest;
+use core::{mem::size_of, slice::from_ref};
 
-use crate::{paranoid_hash::Hasher, Error, Result};
+use sha1::digest::core_api::Buffer;
+
+use crate::{
+    paranoid_hash::{
+        state::{StateReader, StateWriter},
+        BitHasher, Hasher,
+    },
+    Error, Result,
+};
 
 use super::{SHA1_BLOCK_SIZE, SHA1_DIGEST_SIZE};
 
-#[derive(Clone, Debug, Default)]
+// Hashes the same way as `sha1::Sha1`, with the block buffer and the padding of the RustCrypto
+// crates around `sha1::compress`, but keeps the chaining value itself so it can be exported in the
+// same layout as the one in mine.
+#[derive(Clone, Debug)]
 pub struct SHA1 {
-    state: sha1::Sha1,
+    state: [u32; Self::DIGEST_SIZE / size_of::<u32>()],
+    count: u64,
+    buffer: Buffer<sha1::Sha1Core>,
     is_done: bool,
     digest: [u8; Self::DIGEST_SIZE],
 }
@@ -17,21 +30,26 @@ impl Hasher for SHA1 {
             return Err(Error::UpdatingAfterFinished);
         }
 
-        self.state.update(data);
+        add!("checked", self.count, data.len() as u64, u64);
+
+        let state = &mut self.state;
+        self.buffer
+            .digest_blocks(data, |blocks| sha1::compress(state, blocks));
         Ok(())
     }
 
     fn update_last(&mut self, data: &[u8]) -> Result<()> {
-        if self.is_done {
-            return Err(Error::UpdatingAfterFinished);
-        }
+        self.update(data)?;
 
         self.is_done = true;
 
-        self.state.update(data);
-        let state = self.state.clone();
-        let digest = state.finalize();
-        self.digest.copy_from_slice(&digest[..]);
+        let mut state = self.state;
+        self.buffer.len64_padding_be(self.count, |block| {
+            sha1::compress(&mut state, from_ref(block))
+        });
+        for (chunk, word) in self.digest.chunks_exact_mut(4).zip(state) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
         Ok(())
     }
 
@@ -54,6 +72,63 @@ impl Hasher for SHA1 {
     fn digest_size(&self) -> usize {
         Self::DIGEST_SIZE
     }
+
+    // Same fields as mine, which never holds a partial block, so the states are interchangeable.
+    fn save_state(&self) -> Result<alloc::vec::Vec<u8>> {
+        if self.buffer.get_pos() != 0 {
+            return Err(Error::DataLengthMismatched(
+                self.buffer.get_pos(),
+                Self::BLOCK_SIZE,
+            ));
+        }
+
+        let mut writer = StateWriter::new("SHA1");
+        writer.write(&self.state);
+        writer.write(&self.count);
+        writer.write(&self.is_done);
+        writer.write(&self.digest);
+        Ok(writer.finish())
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> Result<()> {
+        let mut reader = StateReader::new(state, "SHA1")?;
+        let hash_state = reader.read()?;
+        let count: u64 = reader.read()?;
+        let is_done: bool = reader.read()?;
+        let digest = reader.read()?;
+        reader.finish()?;
+
+        if !is_done && !count.is_multiple_of(Self::BLOCK_SIZE as u64 * 8) {
+            return Err(Error::InvalidState(
+                "count is not a multiple of the block size",
+            ));
+        }
+
+        *self = Self {
+            state: hash_state,
+            count,
+            buffer: Default::default(),
+            is_done,
+            digest,
+        };
+        Ok(())
+    }
+
+    impl_box_clone!();
+}
+
+impl BitHasher for SHA1 {
+    fn update_last_bits(&mut self, data: &[u8], bit_len: usize) -> Result<()> {
+        if data.len() != bit_len.div_ceil(8) {
+            return Err(Error::BitLengthMismatched(bit_len, data.len()));
+        }
+
+        if !bit_len.is_multiple_of(8) {
+            return Err(Error::PartialBitsUnsupported);
+        }
+
+        self.update_last(data)
+    }
 }
 
 impl SHA1 {
@@ -62,9 +137,22 @@ impl SHA1 {
 
     pub fn new() -> Self {
         Self {
-            state: sha1::Sha1::new(),
+            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0],
+            count: 0,
+            buffer: Default::default(),
             is_done: false,
             digest: [0; Self::DIGEST_SIZE],
         }
     }
+
+    /// Hashes `data` in one go, usable in const contexts. Uses the native implementation.
+    pub const fn hash_const(data: &[u8]) -> [u8; Self::DIGEST_SIZE] {
+        super::mine::SHA1::hash_const(data)
+    }
+}
+
+impl Default for SHA1 {
+    fn default() -> Self {
+        Self::new()
+    }
 }
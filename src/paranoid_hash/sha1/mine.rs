@@ -1,9 +1,12 @@
-use std::mem::size_of;
+use core::mem::size_of;
 
 use super::{SHA1_BLOCK_SIZE, SHA1_DIGEST_SIZE};
-use crate::{paranoid_hash::Hasher, Error, Result};
+use crate::{
+    paranoid_hash::{hash_helper::write_u32_const, BitHasher, Hasher},
+    Error, Result,
+};
 
-#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct SHA1 {
     state: [u32; Self::U32_DIGEST_SIZE],
     count: u64,
@@ -39,6 +42,25 @@ impl Hasher for SHA1 {
     fn digest_size(&self) -> usize {
         Self::DIGEST_SIZE
     }
+
+    impl_state!("SHA1", state, count, is_done, digest);
+
+    impl_box_clone!();
+}
+
+impl BitHasher for SHA1 {
+    fn update_last_bits(&mut self, data: &[u8], bit_len: usize) -> Result<()> {
+        transmute_update_last_bits!(
+            self,
+            data,
+            bit_len,
+            Self::BLOCK_SIZE,
+            u32,
+            u64,
+            "checked",
+            "be"
+        );
+    }
 }
 
 impl SHA1 {
@@ -57,72 +79,86 @@ impl SHA1 {
         }
     }
 
+    /// Hashes `data` in one go, usable in const contexts.
+    pub const fn hash_const(data: &[u8]) -> [u8; Self::DIGEST_SIZE] {
+        let hasher = md_hash_const!(Self::new(), data, Self::BLOCK_SIZE, read_u32_const, 8, true);
+        write_u32_const(&hasher.state, true)
+    }
+
     #[inline]
-    fn update_block(&mut self, block: &[u32; Self::U32_BLOCK_SIZE]) {
+    const fn update_block(&mut self, block: &[u32; Self::U32_BLOCK_SIZE]) {
         let mut w = [0u32; 80];
-        w[0..block.len()].copy_from_slice(block);
-
-        for i in block.len()..w.len() {
-            let temp = w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16];
-            w[i] = temp.rotate_left(1);
+        let mut i = 0;
+        while i < w.len() {
+            w[i] = if i < block.len() {
+                block[i]
+            } else {
+                (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1)
+            };
+            i += 1;
         }
 
         let [mut a, mut b, mut c, mut d, mut e] = self.state;
 
-        for w in w.iter().take(20) {
+        let mut i = 0;
+        while i < 20 {
             let temp = a
                 .rotate_left(5)
                 .wrapping_add((b & c) | (!b & d))
                 .wrapping_add(e)
-                .wrapping_add(*w)
+                .wrapping_add(w[i])
                 .wrapping_add(K[0]);
             e = d;
             d = c;
             c = b.rotate_left(30);
             b = a;
             a = temp;
+            i += 1;
         }
 
-        for w in w.iter().skip(20).take(20) {
+        while i < 40 {
             let temp = a
                 .rotate_left(5)
                 .wrapping_add(b ^ c ^ d)
                 .wrapping_add(e)
-                .wrapping_add(*w)
+                .wrapping_add(w[i])
                 .wrapping_add(K[1]);
             e = d;
             d = c;
             c = b.rotate_left(30);
             b = a;
             a = temp;
+            i += 1;
         }
 
-        for w in w.iter().skip(40).take(20) {
+        while i < 60 {
             let temp = a
                 .rotate_left(5)
                 .wrapping_add((b & c) | (b & d) | (c & d))
                 .wrapping_add(e)
-                .wrapping_add(*w)
+                .wrapping_add(w[i])
                 .wrapping_add(K[2]);
             e = d;
             d = c;
             c = b.rotate_left(30);
             b = a;
             a = temp;
+            i += 1;
         }
 
-        for w in w.iter().skip(60).take(20) {
+        while i < 80 {
             let temp = a
                 .rotate_left(5)
                 .wrapping_add(b ^ c ^ d)
                 .wrapping_add(e)
-                .wrapping_add(*w)
+                .wrapping_add(w[i])
                 .wrapping_add(K[3]);
             e = d;
             d = c;
             c = b.rotate_left(30);
             b = a;
             a = temp;
+            i += 1;
         }
 
         self.state[0] = self.state[0].wrapping_add(a);
@@ -133,4 +169,10 @@ impl SHA1 {
     }
 }
 
+impl Default for SHA1 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 const K: [u32; 4] = [0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xca62c1d6];
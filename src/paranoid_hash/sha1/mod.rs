@@ -1,12 +1,14 @@
 const SHA1_BLOCK_SIZE: usize = 64;
 const SHA1_DIGEST_SIZE: usize = 20;
 
+#[cfg(feature = "alter-impl")]
+pub(crate) mod alter;
+pub(crate) mod mine;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "alter-impl")] {
-        mod alter;
         pub use alter::SHA1;
     } else {
-        mod mine;
         pub use self::mine::SHA1;
     }
 }
@@ -14,8 +16,8 @@ cfg_if::cfg_if! {
 #[cfg(test)]
 mod tests {
     use crate::paranoid_hash::{
-        tester::{HasherTestWrapper, TestData},
-        Hasher,
+        tester::{run_bit_tests, run_const_tests, BitTestData, HasherTestWrapper, TestData},
+        BitHasher, Hasher,
     };
 
     use super::SHA1;
@@ -74,11 +76,78 @@ mod tests {
         },
     ];
 
+    #[test]
+    fn const_tests() {
+        const DIGEST: [u8; SHA1::DIGEST_SIZE] = SHA1::hash_const(b"abc");
+        assert_eq!(
+            hex::encode(DIGEST),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+
+        run_const_tests(SHA1::new(), SHA1::hash_const);
+    }
+
     #[test]
     fn tests_from_rfc() {
         HasherTestWrapper::new(SHA1::new()).run_tests(TESTS);
     }
 
+    const BIT_TESTS: &[BitTestData] = &[
+        BitTestData {
+            data: &[0x98],
+            bit_len: 5,
+            result: "29826b003b906e660eff4027ce98af3531ac75ba",
+        },
+        BitTestData {
+            data: &[
+                0x1c, 0x2e, 0x2b, 0xb8, 0x56, 0x9d, 0x80, 0x6c, 0x12, 0x51, 0xdc, 0xc9, 0xbe, 0xe3,
+                0x89, 0x12, 0x0e, 0xba, 0xee, 0xa3, 0xc2, 0xd8, 0x54, 0x5a, 0x78, 0x76, 0x0c, 0x5a,
+                0xa6, 0x58, 0x45, 0xb8, 0x5d, 0xe4, 0xd4, 0xba, 0xb5, 0xb9, 0xe4, 0x52, 0xcc, 0xec,
+                0x7f, 0xfa, 0x8e, 0xff, 0xb5, 0xe8, 0xec, 0xb3, 0xe9, 0xf9, 0x71, 0xa6, 0x55, 0x89,
+            ],
+            bit_len: 447,
+            result: "689f766f3c97c73eed678955e49a4b7935162313",
+        },
+        BitTestData {
+            data: &[
+                0xf5, 0x9e, 0x9b, 0xd0, 0x9f, 0x6a, 0xfa, 0xbb, 0x26, 0xae, 0x04, 0x61, 0x36, 0x1e,
+                0x19, 0x8b, 0x74, 0x36, 0x45, 0x88, 0x7d, 0x6b, 0x1e, 0xd8, 0x10, 0x1d, 0xb9, 0xb8,
+                0x58, 0x7f, 0x0c, 0x2a, 0x3a, 0x22, 0x0c, 0x14, 0x0a, 0xbf, 0x82, 0x41, 0x50, 0x5e,
+                0x00, 0xc5, 0x16, 0x7e, 0x4d, 0x12, 0x02, 0xb0, 0x39, 0x92, 0xac, 0xfa, 0x0f, 0x9d,
+                0xe5, 0x17, 0x87, 0xcd, 0x4e, 0xf2, 0x73, 0x2f,
+            ],
+            bit_len: 511,
+            result: "4334fb935d8795fb727432967eaedb2611dbf5c7",
+        },
+        BitTestData {
+            data: "abc".as_bytes(),
+            bit_len: 24,
+            result: "a9993e364706816aba3e25717850c26c9cd0d89d",
+        },
+    ];
+
+    #[cfg(not(feature = "alter-impl"))]
+    #[test]
+    fn bit_tests() {
+        run_bit_tests(SHA1::new(), BIT_TESTS);
+    }
+
+    #[cfg(feature = "alter-impl")]
+    #[test]
+    fn bit_tests() {
+        run_bit_tests(SHA1::new(), &BIT_TESTS[3..]);
+
+        let mut hasher = SHA1::new();
+        assert!(hasher.update_last_bits(&[0x98], 5).is_err());
+    }
+
+    #[test]
+    fn bit_length_mismatch_test() {
+        let mut hasher = SHA1::new();
+        assert!(hasher.update_last_bits(&[0x98, 0x00], 5).is_err());
+        assert!(hasher.update_last_bits(&[], 5).is_err());
+    }
+
     #[cfg(not(feature = "alter-impl"))]
     #[test]
     #[should_panic]
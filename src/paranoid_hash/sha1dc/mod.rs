@@ -0,0 +1,340 @@
+mod ubc_check;
+
+use core::mem::size_of;
+
+use crate::{paranoid_hash::Hasher, Error, Result};
+
+use ubc_check::{ubc_check, DISTURBANCE_VECTORS};
+
+/// SHA-1 with collision detection, see <https://github.com/cr-marcstevens/sha1collisiondetection>.
+///
+/// Every block is checked against the known disturbance vectors, if a block is part of a
+/// collision attack, `update_last` returns [`Error::CollisionDetected`]. The digest is still
+/// available afterwards, and when safe hash is enabled it is the safe hash instead of the
+/// colliding SHA-1 value.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SHA1DC {
+    state: [u32; Self::U32_DIGEST_SIZE],
+    count: u64,
+    is_done: bool,
+    digest: [u8; Self::DIGEST_SIZE],
+    safe_hash: bool,
+    collision_detected: bool,
+}
+
+impl Hasher for SHA1DC {
+    fn update(&mut self, data: &[u8]) -> Result<()> {
+        transmute_update!(self, data, Self::BLOCK_SIZE, u32, u64, "checked", "be");
+    }
+
+    fn update_last(&mut self, data: &[u8]) -> Result<()> {
+        self.finish(data)?;
+
+        if self.collision_detected {
+            return Err(Error::CollisionDetected);
+        }
+
+        Ok(())
+    }
+
+    fn digest(&self) -> Result<&[u8]> {
+        if !self.is_done {
+            return Err(Error::NotFinished);
+        }
+
+        Ok(&self.digest)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::with_safe_hash(self.safe_hash);
+    }
+
+    fn block_size(&self) -> usize {
+        Self::BLOCK_SIZE
+    }
+
+    fn digest_size(&self) -> usize {
+        Self::DIGEST_SIZE
+    }
+
+    impl_state!(
+        "SHA1DC",
+        state,
+        count,
+        is_done,
+        digest,
+        safe_hash,
+        collision_detected
+    );
+
+    impl_box_clone!();
+}
+
+impl SHA1DC {
+    pub const BLOCK_SIZE: usize = 64;
+    pub const DIGEST_SIZE: usize = 20;
+
+    const U32_BLOCK_SIZE: usize = Self::BLOCK_SIZE / size_of::<u32>();
+    const U32_DIGEST_SIZE: usize = Self::DIGEST_SIZE / size_of::<u32>();
+
+    pub const fn new() -> Self {
+        Self::with_safe_hash(true)
+    }
+
+    /// When `safe_hash` is true, blocks which are part of a collision attack are compressed two
+    /// more times, so the colliding messages get different digests.
+    pub const fn with_safe_hash(safe_hash: bool) -> Self {
+        Self {
+            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0],
+            count: 0,
+            is_done: false,
+            digest: [0; Self::DIGEST_SIZE],
+            safe_hash,
+            collision_detected: false,
+        }
+    }
+
+    pub const fn collision_detected(&self) -> bool {
+        self.collision_detected
+    }
+
+    fn finish(&mut self, data: &[u8]) -> Result<()> {
+        transmute_update_last!(self, data, Self::BLOCK_SIZE, u32, u64, "checked", "be");
+    }
+
+    #[inline]
+    fn update_block(&mut self, block: &[u32; Self::U32_BLOCK_SIZE]) {
+        let mut w = [0u32; 80];
+        w[0..block.len()].copy_from_slice(block);
+
+        for i in block.len()..w.len() {
+            let temp = w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16];
+            w[i] = temp.rotate_left(1);
+        }
+
+        let [state_58, state_65] = compress(&mut self.state, &w);
+
+        let mask = ubc_check(&w);
+        if mask == 0 {
+            return;
+        }
+
+        for (i, dv) in DISTURBANCE_VECTORS.iter().enumerate() {
+            if mask & (1 << i) == 0 {
+                continue;
+            }
+
+            let mut w2 = w;
+            for (w2, dm) in w2.iter_mut().zip(dv.dm.iter()) {
+                *w2 ^= dm;
+            }
+
+            let state = match dv.test_step {
+                58 => state_58,
+                _ => state_65,
+            };
+
+            if recompress(state, &w2, dv.test_step) == self.state {
+                self.collision_detected = true;
+
+                if self.safe_hash {
+                    compress(&mut self.state, &w);
+                    compress(&mut self.state, &w);
+                }
+
+                break;
+            }
+        }
+    }
+}
+
+impl Default for SHA1DC {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compresses the expanded message block into `ihv`, returns the working states before step 58 and 65.
+#[inline(always)]
+fn compress(ihv: &mut [u32; 5], w: &[u32; 80]) -> [[u32; 5]; 2] {
+    let mut working = *ihv;
+    let mut saved = [[0u32; 5]; 2];
+
+    for (t, w) in w.iter().enumerate() {
+        match t {
+            58 => saved[0] = working,
+            65 => saved[1] = working,
+            _ => {}
+        }
+
+        working = step_forward(working, *w, t);
+    }
+
+    for (s, t) in ihv.iter_mut().zip(working.iter()) {
+        *s = s.wrapping_add(*t);
+    }
+
+    saved
+}
+
+/// Recomputes the chaining value from the working state before step `test_step`, using the
+/// message block with the disturbance vector applied.
+#[inline(always)]
+fn recompress(state: [u32; 5], w: &[u32; 80], test_step: usize) -> [u32; 5] {
+    let mut ihv = state;
+    for t in (0..test_step).rev() {
+        ihv = step_backward(ihv, w[t], t);
+    }
+
+    let mut working = state;
+    for (t, w) in w.iter().enumerate().skip(test_step) {
+        working = step_forward(working, *w, t);
+    }
+
+    for (s, t) in ihv.iter_mut().zip(working.iter()) {
+        *s = s.wrapping_add(*t);
+    }
+
+    ihv
+}
+
+#[inline(always)]
+fn step_forward([a, b, c, d, e]: [u32; 5], w: u32, t: usize) -> [u32; 5] {
+    let temp = a
+        .rotate_left(5)
+        .wrapping_add(hash(b, c, d, t))
+        .wrapping_add(e)
+        .wrapping_add(w)
+        .wrapping_add(K[t / 20]);
+
+    [temp, a, b.rotate_left(30), c, d]
+}
+
+#[inline(always)]
+fn step_backward([temp, a, b, c, d]: [u32; 5], w: u32, t: usize) -> [u32; 5] {
+    let b = b.rotate_right(30);
+    let e = temp.wrapping_sub(
+        a.rotate_left(5)
+            .wrapping_add(hash(b, c, d, t))
+            .wrapping_add(w)
+            .wrapping_add(K[t / 20]),
+    );
+
+    [a, b, c, d, e]
+}
+
+#[inline(always)]
+fn hash(b: u32, c: u32, d: u32, t: usize) -> u32 {
+    match t / 20 {
+        0 => (b & c) | (!b & d),
+        2 => (b & c) | (b & d) | (c & d),
+        _ => b ^ c ^ d,
+    }
+}
+
+const K: [u32; 4] = [0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xca62c1d6];
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        paranoid_hash::{
+            tester::{HasherTestWrapper, TestData, SHATTERED_1, SHATTERED_2},
+            Hasher,
+        },
+        Error,
+    };
+
+    use super::SHA1DC;
+
+    const TESTS: &[TestData] = &[
+        TestData {
+            data: "abc".as_bytes(),
+            repeat: 1,
+            result: "a9993e364706816aba3e25717850c26c9cd0d89d",
+        },
+        TestData {
+            data: "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq".as_bytes(),
+            repeat: 1,
+            result: "84983e441c3bd26ebaae4aa1f95129e5e54670f1",
+        },
+        TestData {
+            data: "a".as_bytes(),
+            repeat: 1000000,
+            result: "34aa973cd4c4daa4f61eeb2bdbad27316534016f",
+        },
+        TestData {
+            data: "0123456701234567012345670123456701234567012345670123456701234567".as_bytes(),
+            repeat: 10,
+            result: "dea356a2cddd90c7a7ecedc5ebb563934f460452",
+        },
+    ];
+
+    fn hash_collision(mut hasher: SHA1DC, data: &str) -> String {
+        let data = hex::decode(data).unwrap();
+        hasher.update(&data).unwrap();
+        assert!(matches!(
+            hasher.update_last(&[]),
+            Err(Error::CollisionDetected)
+        ));
+        assert!(hasher.collision_detected());
+        hex::encode(hasher.digest().unwrap())
+    }
+
+    #[test]
+    fn tests_from_rfc() {
+        HasherTestWrapper::new(SHA1DC::new()).run_tests(TESTS);
+
+        HasherTestWrapper::new(SHA1DC::with_safe_hash(false)).run_tests(TESTS);
+    }
+
+    #[test]
+    fn collision_test() {
+        assert_eq!(
+            hash_collision(SHA1DC::new(), SHATTERED_1),
+            "7117b3cb9225aaf0d8ef1a40e493957b0bf8693d"
+        );
+        assert_eq!(
+            hash_collision(SHA1DC::new(), SHATTERED_2),
+            "29f38ae9fd98e2931120fa0bf213e024250d3f6a"
+        );
+
+        // without safe hash, the colliding SHA-1 digest is kept
+        assert_eq!(
+            hash_collision(SHA1DC::with_safe_hash(false), SHATTERED_1),
+            "f92d74e3874587aaf443d1db961d4e26dde13e9c"
+        );
+        assert_eq!(
+            hash_collision(SHA1DC::with_safe_hash(false), SHATTERED_2),
+            "f92d74e3874587aaf443d1db961d4e26dde13e9c"
+        );
+    }
+
+    #[test]
+    fn reset_test() {
+        let mut hasher = SHA1DC::with_safe_hash(false);
+        hasher.update(&hex::decode(SHATTERED_1).unwrap()).unwrap();
+        assert!(hasher.update_last(&[]).is_err());
+
+        // reset should clear the detection result but keep the safe hash setting
+        hasher.reset();
+        assert!(!hasher.collision_detected());
+        assert_eq!(hasher, SHA1DC::with_safe_hash(false));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test1() {
+        let mut hasher = SHA1DC::new();
+        hasher
+            .update("Not multiple of block size".as_bytes())
+            .unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_test2() {
+        let mut hasher = SHA1DC::new();
+        let data = [0u8; SHA1DC::BLOCK_SIZE + 1];
+        hasher.update_last(&data).unwrap();
+    }
+}
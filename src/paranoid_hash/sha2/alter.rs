@@ -1,14 +1,31 @@
-use sha2::Digest;
-
-use crate::{paranoid_hash::Hasher, Error, Result};
-
+use core::slice::from_ref;
+
+use sha2::digest::core_api::Buffer;
+
+use super::{
+    SHA224_INITIAL, SHA256_BLOCK_SIZE, SHA256_DIGEST_SIZE, SHA256_INITIAL, SHA384_INITIAL,
+    SHA512_BLOCK_SIZE, SHA512_DIGEST_SIZE, SHA512_INITIAL, STATE_SIZE_IN_WORD,
+};
+use crate::{
+    paranoid_hash::{
+        state::{StateReader, StateWriter},
+        BitHasher, Hasher,
+    },
+    Error, Result,
+};
+
+// Hashes the same way as the `sha2` crate, with the block buffer and the padding of the RustCrypto
+// crates around its compression functions, but keeps the chaining value itself so it can be
+// exported in the same layout as the one in mine.
 macro_rules! create_sha2 {
-    ( $struct:ident, $base:ty, $bs:expr, $ds:expr ) => {
+    ( $struct:ident, $word:ty, $count:ty, $core:ty, $compress:path, $padding:ident, $initial:expr, $bs:expr, $ds:expr, $full_ds:expr ) => {
         #[derive(Clone, Debug)]
         pub struct $struct {
-            state: $base,
+            state: [$word; STATE_SIZE_IN_WORD],
+            count: $count,
+            buffer: Buffer<$core>,
             is_done: bool,
-            digest: [u8; Self::DIGEST_SIZE],
+            digest: [u8; $full_ds],
         }
 
         impl $struct {
@@ -17,11 +34,18 @@ macro_rules! create_sha2 {
 
             pub fn new() -> Self {
                 Self {
-                    state: <$base>::new(),
+                    state: $initial,
+                    count: 0,
+                    buffer: Default::default(),
                     is_done: false,
-                    digest: [0; Self::DIGEST_SIZE],
+                    digest: [0; $full_ds],
                 }
             }
+
+            /// Hashes `data` in one go, usable in const contexts. Uses the native implementation.
+            pub const fn hash_const(data: &[u8]) -> [u8; Self::DIGEST_SIZE] {
+                super::mine::$struct::hash_const(data)
+            }
         }
 
         impl Hasher for $struct {
@@ -30,21 +54,29 @@ macro_rules! create_sha2 {
                     return Err(Error::UpdatingAfterFinished);
                 }
 
-                self.state.update(data);
+                add!("checked", self.count, data.len() as $count, $count);
+
+                let state = &mut self.state;
+                self.buffer
+                    .digest_blocks(data, |blocks| $compress(state, blocks));
                 Ok(())
             }
 
             fn update_last(&mut self, data: &[u8]) -> Result<()> {
-                if self.is_done {
-                    return Err(Error::UpdatingAfterFinished);
-                }
+                self.update(data)?;
 
                 self.is_done = true;
 
-                self.state.update(data);
-                let state = self.state.clone();
-                let digest = state.finalize();
-                self.digest.copy_from_slice(&digest[..]);
+                let mut state = self.state;
+                self.buffer
+                    .$padding(self.count, |block| $compress(&mut state, from_ref(block)));
+                for (chunk, word) in self
+                    .digest
+                    .chunks_exact_mut(core::mem::size_of::<$word>())
+                    .zip(state)
+                {
+                    chunk.copy_from_slice(&word.to_be_bytes());
+                }
                 Ok(())
             }
 
@@ -53,7 +85,7 @@ macro_rules! create_sha2 {
                     return Err(Error::NotFinished);
                 }
 
-                Ok(&self.digest)
+                Ok(&self.digest[..Self::DIGEST_SIZE])
             }
 
             fn reset(&mut self) {
@@ -67,6 +99,61 @@ macro_rules! create_sha2 {
             fn digest_size(&self) -> usize {
                 Self::DIGEST_SIZE
             }
+
+            // Same fields as mine, which never holds a partial block, so the states are
+            // interchangeable.
+            fn save_state(&self) -> Result<alloc::vec::Vec<u8>> {
+                if self.buffer.get_pos() != 0 {
+                    return Err(Error::DataLengthMismatched(self.buffer.get_pos(), $bs));
+                }
+
+                let mut writer = StateWriter::new(stringify!($struct));
+                writer.write(&self.state);
+                writer.write(&self.count);
+                writer.write(&self.is_done);
+                writer.write(&self.digest);
+                Ok(writer.finish())
+            }
+
+            fn restore_state(&mut self, state: &[u8]) -> Result<()> {
+                let mut reader = StateReader::new(state, stringify!($struct))?;
+                let hash_state = reader.read()?;
+                let count: $count = reader.read()?;
+                let is_done: bool = reader.read()?;
+                let digest = reader.read()?;
+                reader.finish()?;
+
+                if !is_done && !count.is_multiple_of($bs as $count * 8) {
+                    return Err(Error::InvalidState(
+                        "count is not a multiple of the block size",
+                    ));
+                }
+
+                *self = Self {
+                    state: hash_state,
+                    count,
+                    buffer: Default::default(),
+                    is_done,
+                    digest,
+                };
+                Ok(())
+            }
+
+            impl_box_clone!();
+        }
+
+        impl BitHasher for $struct {
+            fn update_last_bits(&mut self, data: &[u8], bit_len: usize) -> Result<()> {
+                if data.len() != bit_len.div_ceil(8) {
+                    return Err(Error::BitLengthMismatched(bit_len, data.len()));
+                }
+
+                if !bit_len.is_multiple_of(8) {
+                    return Err(Error::PartialBitsUnsupported);
+                }
+
+                self.update_last(data)
+            }
         }
 
         impl Default for $struct {
@@ -77,10 +164,54 @@ macro_rules! create_sha2 {
     };
 }
 
-create_sha2!(SHA2_224, sha2::Sha224, super::SHA256_BLOCK_SIZE, 224 / 8);
-
-create_sha2!(SHA2_256, sha2::Sha256, super::SHA256_BLOCK_SIZE, 256 / 8);
-
-create_sha2!(SHA2_384, sha2::Sha384, super::SHA512_BLOCK_SIZE, 384 / 8);
-
-create_sha2!(SHA2_512, sha2::Sha512, super::SHA512_BLOCK_SIZE, 512 / 8);
+create_sha2!(
+    SHA2_224,
+    u32,
+    u64,
+    sha2::Sha256VarCore,
+    sha2::compress256,
+    len64_padding_be,
+    SHA224_INITIAL,
+    SHA256_BLOCK_SIZE,
+    224 / 8,
+    SHA256_DIGEST_SIZE
+);
+
+create_sha2!(
+    SHA2_256,
+    u32,
+    u64,
+    sha2::Sha256VarCore,
+    sha2::compress256,
+    len64_padding_be,
+    SHA256_INITIAL,
+    SHA256_BLOCK_SIZE,
+    256 / 8,
+    SHA256_DIGEST_SIZE
+);
+
+create_sha2!(
+    SHA2_384,
+    u64,
+    u128,
+    sha2::Sha512VarCore,
+    sha2::compress512,
+    len128_padding_be,
+    SHA384_INITIAL,
+    SHA512_BLOCK_SIZE,
+    384 / 8,
+    SHA512_DIGEST_SIZE
+);
+
+create_sha2!(
+    SHA2_512,
+    u64,
+    u128,
+    sha2::Sha512VarCore,
+    sha2::compress512,
+    len128_padding_be,
+    SHA512_INITIAL,
+    SHA512_BLOCK_SIZE,
+    512 / 8,
+    SHA512_DIGEST_SIZE
+);
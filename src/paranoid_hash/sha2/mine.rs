@@ -1,9 +1,18 @@
-use std::{mem::size_of, ops::Shr};
+use core::mem::size_of;
 
 use ambassador::Delegate;
 
-use super::{SHA256_BLOCK_SIZE, SHA512_BLOCK_SIZE};
-use crate::{paranoid_hash::Hasher, Error, Result};
+use super::{
+    SHA224_INITIAL, SHA256_BLOCK_SIZE, SHA256_DIGEST_SIZE, SHA256_INITIAL, SHA384_INITIAL,
+    SHA512_BLOCK_SIZE, SHA512_DIGEST_SIZE, SHA512_INITIAL, STATE_SIZE_IN_WORD,
+};
+use crate::{
+    paranoid_hash::{
+        hash_helper::{write_u32_const, write_u64_const},
+        BitHasher, Hasher,
+    },
+    Error, Result,
+};
 
 macro_rules! impl_common {
     ( $struct:ty, $base:ty ) => {
@@ -14,6 +23,19 @@ macro_rules! impl_common {
             pub const fn new() -> Self {
                 Self(<$base>::new())
             }
+
+            /// Hashes `data` in one go, usable in const contexts.
+            pub const fn hash_const(data: &[u8]) -> [u8; Self::DIGEST_SIZE] {
+                let digest = <$base>::hash_const(data);
+                let mut truncated = [0; Self::DIGEST_SIZE];
+                let mut i = 0;
+                while i < truncated.len() {
+                    truncated[i] = digest[i];
+                    i += 1;
+                }
+
+                truncated
+            }
         }
     };
 }
@@ -22,16 +44,22 @@ macro_rules! update_block {
     ( $self:expr, $block:expr, $round:expr, $k:expr ) => {
         let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = $self.state;
 
-        let mut words: [Self::Word; $round] = [0; $round];
-        words[0..$block.len()].copy_from_slice($block);
-        for i in $block.len()..$round {
-            words[i] = Self::ssigma1(words[i - 2])
-                .wrapping_add(words[i - 7])
-                .wrapping_add(Self::ssigma0(words[i - 15]))
-                .wrapping_add(words[i - 16]);
+        let mut words: [<Self as SHA2Core>::Word; $round] = [0; $round];
+        let mut i = 0;
+        while i < $round {
+            words[i] = if i < $block.len() {
+                $block[i]
+            } else {
+                Self::ssigma1(words[i - 2])
+                    .wrapping_add(words[i - 7])
+                    .wrapping_add(Self::ssigma0(words[i - 15]))
+                    .wrapping_add(words[i - 16])
+            };
+            i += 1;
         }
 
-        for i in 0..$round {
+        let mut i = 0;
+        while i < $round {
             let t1 = h
                 .wrapping_add(Self::bsigma1(e))
                 .wrapping_add(Self::ch(e, f, g))
@@ -46,6 +74,7 @@ macro_rules! update_block {
             c = b;
             b = a;
             a = t1.wrapping_add(t2);
+            i += 1;
         }
 
         $self.state[0] = a.wrapping_add($self.state[0]);
@@ -61,21 +90,25 @@ macro_rules! update_block {
 
 #[derive(Delegate, Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[delegate(Hasher)]
+#[delegate(BitHasher)]
 pub struct SHA2_224(SHA2_256Core<224>);
 impl_common!(SHA2_224, SHA2_256Core<224>);
 
 #[derive(Delegate, Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[delegate(Hasher)]
+#[delegate(BitHasher)]
 pub struct SHA2_256(SHA2_256Core<256>);
 impl_common!(SHA2_256, SHA2_256Core<256>);
 
 #[derive(Delegate, Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[delegate(Hasher)]
+#[delegate(BitHasher)]
 pub struct SHA2_384(SHA2_512Core<384>);
 impl_common!(SHA2_384, SHA2_512Core<384>);
 
 #[derive(Delegate, Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[delegate(Hasher)]
+#[delegate(BitHasher)]
 pub struct SHA2_512(SHA2_512Core<512>);
 impl_common!(SHA2_512, SHA2_512Core<512>);
 
@@ -86,17 +119,9 @@ trait SHA2Core {
     const BLOCK_SIZE: usize;
     const DIGEST_SIZE: usize;
     const INITIAL_STATE: [Self::Word; STATE_SIZE_IN_WORD];
-
-    fn ch(x: Self::Word, y: Self::Word, z: Self::Word) -> Self::Word;
-    fn maj(x: Self::Word, y: Self::Word, z: Self::Word) -> Self::Word;
-    fn bsigma0(x: Self::Word) -> Self::Word;
-    fn bsigma1(x: Self::Word) -> Self::Word;
-    fn ssigma0(x: Self::Word) -> Self::Word;
-    fn ssigma1(x: Self::Word) -> Self::Word;
-    fn update_block(&mut self, block: &[Self::Word; BLOCK_SIZE_IN_WORD]);
 }
 
-#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 struct SHA2_256Core<const LENGTH: usize> {
     state: [u32; STATE_SIZE_IN_WORD],
     count: u64,
@@ -105,6 +130,12 @@ struct SHA2_256Core<const LENGTH: usize> {
 }
 
 impl<const LENGTH: usize> SHA2_256Core<LENGTH> {
+    const NAME: &'static str = match LENGTH {
+        224 => "SHA2_224",
+        256 => "SHA2_256",
+        _ => panic!("Invalid length"),
+    };
+
     const fn new() -> Self {
         Self {
             state: Self::INITIAL_STATE,
@@ -115,6 +146,12 @@ impl<const LENGTH: usize> SHA2_256Core<LENGTH> {
     }
 }
 
+impl<const LENGTH: usize> Default for SHA2_256Core<LENGTH> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<const LENGTH: usize> SHA2Core for SHA2_256Core<LENGTH> {
     type Word = u32;
 
@@ -129,39 +166,54 @@ impl<const LENGTH: usize> SHA2Core for SHA2_256Core<LENGTH> {
         256 => SHA256_INITIAL,
         _ => panic!("Invalid length"),
     };
+}
+
+impl<const LENGTH: usize> SHA2_256Core<LENGTH> {
+    // the whole state, the wrappers truncate it to their digest size
+    const fn hash_const(data: &[u8]) -> [u8; SHA256_DIGEST_SIZE] {
+        let hasher = md_hash_const!(
+            Self::new(),
+            data,
+            SHA256_BLOCK_SIZE,
+            read_u32_const,
+            8,
+            true
+        );
+        write_u32_const(&hasher.state, true)
+    }
 
     #[inline]
-    fn ch(x: Self::Word, y: Self::Word, z: Self::Word) -> Self::Word {
+    const fn ch(x: u32, y: u32, z: u32) -> u32 {
         (x & (y ^ z)) ^ z
     }
 
     #[inline]
-    fn maj(x: Self::Word, y: Self::Word, z: Self::Word) -> Self::Word {
+    const fn maj(x: u32, y: u32, z: u32) -> u32 {
         (x & (y | z)) | (y & z)
     }
 
     #[inline]
-    fn bsigma0(x: Self::Word) -> Self::Word {
+    const fn bsigma0(x: u32) -> u32 {
         x.rotate_right(2) ^ x.rotate_right(13) ^ x.rotate_right(22)
     }
 
     #[inline]
-    fn bsigma1(x: Self::Word) -> Self::Word {
+    const fn bsigma1(x: u32) -> u32 {
         x.rotate_right(6) ^ x.rotate_right(11) ^ x.rotate_right(25)
     }
 
     #[inline]
-    fn ssigma0(x: Self::Word) -> Self::Word {
-        x.rotate_right(7) ^ x.rotate_right(18) ^ x.shr(3)
+    const fn ssigma0(x: u32) -> u32 {
+        x.rotate_right(7) ^ x.rotate_right(18) ^ (x >> 3)
     }
 
     #[inline]
-    fn ssigma1(x: Self::Word) -> Self::Word {
-        x.rotate_right(17) ^ x.rotate_right(19) ^ x.shr(10)
+    const fn ssigma1(x: u32) -> u32 {
+        x.rotate_right(17) ^ x.rotate_right(19) ^ (x >> 10)
     }
 
     #[inline]
-    fn update_block(&mut self, block: &[Self::Word; BLOCK_SIZE_IN_WORD]) {
+    const fn update_block(&mut self, block: &[u32; BLOCK_SIZE_IN_WORD]) {
         update_block!(self, block, SHA256_ROUND_COUNT, K256);
     }
 }
@@ -200,6 +252,26 @@ impl<const LENGTH: usize> Hasher for SHA2_256Core<LENGTH> {
     fn digest_size(&self) -> usize {
         Self::DIGEST_SIZE
     }
+
+    impl_state!(Self::NAME, state, count, is_done, digest);
+
+    impl_box_clone!();
+}
+
+impl<const LENGTH: usize> BitHasher for SHA2_256Core<LENGTH> {
+    #[inline]
+    fn update_last_bits(&mut self, data: &[u8], bit_len: usize) -> Result<()> {
+        transmute_update_last_bits!(
+            self,
+            data,
+            bit_len,
+            SHA256_BLOCK_SIZE,
+            u32,
+            u64,
+            "checked",
+            "be"
+        );
+    }
 }
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -211,6 +283,12 @@ struct SHA2_512Core<const LENGTH: usize> {
 }
 
 impl<const LENGTH: usize> SHA2_512Core<LENGTH> {
+    const NAME: &'static str = match LENGTH {
+        384 => "SHA2_384",
+        512 => "SHA2_512",
+        _ => panic!("Invalid length"),
+    };
+
     const fn new() -> Self {
         Self {
             state: Self::INITIAL_STATE,
@@ -235,39 +313,54 @@ impl<const LENGTH: usize> SHA2Core for SHA2_512Core<LENGTH> {
         512 => SHA512_INITIAL,
         _ => panic!("Invalid length"),
     };
+}
+
+impl<const LENGTH: usize> SHA2_512Core<LENGTH> {
+    // the whole state, the wrappers truncate it to their digest size
+    const fn hash_const(data: &[u8]) -> [u8; SHA512_DIGEST_SIZE] {
+        let hasher = md_hash_const!(
+            Self::new(),
+            data,
+            SHA512_BLOCK_SIZE,
+            read_u64_const,
+            16,
+            true
+        );
+        write_u64_const(&hasher.state, true)
+    }
 
     #[inline]
-    fn ch(x: Self::Word, y: Self::Word, z: Self::Word) -> Self::Word {
+    const fn ch(x: u64, y: u64, z: u64) -> u64 {
         (x & (y ^ z)) ^ z
     }
 
     #[inline]
-    fn maj(x: Self::Word, y: Self::Word, z: Self::Word) -> Self::Word {
+    const fn maj(x: u64, y: u64, z: u64) -> u64 {
         (x & (y | z)) | (y & z)
     }
 
     #[inline]
-    fn bsigma0(x: Self::Word) -> Self::Word {
+    const fn bsigma0(x: u64) -> u64 {
         x.rotate_right(28) ^ x.rotate_right(34) ^ x.rotate_right(39)
     }
 
     #[inline]
-    fn bsigma1(x: Self::Word) -> Self::Word {
+    const fn bsigma1(x: u64) -> u64 {
         x.rotate_right(14) ^ x.rotate_right(18) ^ x.rotate_right(41)
     }
 
     #[inline]
-    fn ssigma0(x: Self::Word) -> Self::Word {
-        x.rotate_right(1) ^ x.rotate_right(8) ^ x.shr(7)
+    const fn ssigma0(x: u64) -> u64 {
+        x.rotate_right(1) ^ x.rotate_right(8) ^ (x >> 7)
     }
 
     #[inline]
-    fn ssigma1(x: Self::Word) -> Self::Word {
-        x.rotate_right(19) ^ x.rotate_right(61) ^ x.shr(6)
+    const fn ssigma1(x: u64) -> u64 {
+        x.rotate_right(19) ^ x.rotate_right(61) ^ (x >> 6)
     }
 
     #[inline]
-    fn update_block(&mut self, block: &[Self::Word; BLOCK_SIZE_IN_WORD]) {
+    const fn update_block(&mut self, block: &[u64; BLOCK_SIZE_IN_WORD]) {
         update_block!(self, block, SHA512_ROUND_COUNT, K512);
     }
 }
@@ -306,6 +399,26 @@ impl<const LENGTH: usize> Hasher for SHA2_512Core<LENGTH> {
     fn digest_size(&self) -> usize {
         Self::DIGEST_SIZE
     }
+
+    impl_state!(Self::NAME, state, count, is_done, digest);
+
+    impl_box_clone!();
+}
+
+impl<const LENGTH: usize> BitHasher for SHA2_512Core<LENGTH> {
+    #[inline]
+    fn update_last_bits(&mut self, data: &[u8], bit_len: usize) -> Result<()> {
+        transmute_update_last_bits!(
+            self,
+            data,
+            bit_len,
+            SHA512_BLOCK_SIZE,
+            u64,
+            u128,
+            "checked",
+            "be"
+        );
+    }
 }
 
 impl<const LENGTH: usize> Default for SHA2_512Core<LENGTH> {
@@ -314,43 +427,10 @@ impl<const LENGTH: usize> Default for SHA2_512Core<LENGTH> {
     }
 }
 
-const STATE_SIZE_IN_WORD: usize = 8;
 const BLOCK_SIZE_IN_WORD: usize = 16;
-const SHA256_DIGEST_SIZE: usize = 256 / 8;
-const SHA512_DIGEST_SIZE: usize = 512 / 8;
 const SHA256_ROUND_COUNT: usize = 64;
 const SHA512_ROUND_COUNT: usize = 80;
 
-const SHA224_INITIAL: [u32; STATE_SIZE_IN_WORD] = [
-    0xc1059ed8, 0x367cd507, 0x3070dd17, 0xf70e5939, 0xffc00b31, 0x68581511, 0x64f98fa7, 0xbefa4fa4,
-];
-
-const SHA256_INITIAL: [u32; STATE_SIZE_IN_WORD] = [
-    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
-];
-
-const SHA384_INITIAL: [u64; STATE_SIZE_IN_WORD] = [
-    0xcbbb9d5dc1059ed8,
-    0x629a292a367cd507,
-    0x9159015a3070dd17,
-    0x152fecd8f70e5939,
-    0x67332667ffc00b31,
-    0x8eb44a8768581511,
-    0xdb0c2e0d64f98fa7,
-    0x47b5481dbefa4fa4,
-];
-
-const SHA512_INITIAL: [u64; STATE_SIZE_IN_WORD] = [
-    0x6a09e667f3bcc908,
-    0xbb67ae8584caa73b,
-    0x3c6ef372fe94f82b,
-    0xa54ff53a5f1d36f1,
-    0x510e527fade682d1,
-    0x9b05688c2b3e6c1f,
-    0x1f83d9abfb41bd6b,
-    0x5be0cd19137e2179,
-];
-
 const K256: [u32; SHA256_ROUND_COUNT] = [
     0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
     0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
@@ -1,18 +1,56 @@
 const SHA256_BLOCK_SIZE: usize = 64;
 const SHA512_BLOCK_SIZE: usize = 128;
+const STATE_SIZE_IN_WORD: usize = 8;
+const SHA256_DIGEST_SIZE: usize = 256 / 8;
+const SHA512_DIGEST_SIZE: usize = 512 / 8;
+
+const SHA224_INITIAL: [u32; STATE_SIZE_IN_WORD] = [
+    0xc1059ed8, 0x367cd507, 0x3070dd17, 0xf70e5939, 0xffc00b31, 0x68581511, 0x64f98fa7, 0xbefa4fa4,
+];
+
+const SHA256_INITIAL: [u32; STATE_SIZE_IN_WORD] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const SHA384_INITIAL: [u64; STATE_SIZE_IN_WORD] = [
+    0xcbbb9d5dc1059ed8,
+    0x629a292a367cd507,
+    0x9159015a3070dd17,
+    0x152fecd8f70e5939,
+    0x67332667ffc00b31,
+    0x8eb44a8768581511,
+    0xdb0c2e0d64f98fa7,
+    0x47b5481dbefa4fa4,
+];
+
+const SHA512_INITIAL: [u64; STATE_SIZE_IN_WORD] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+#[cfg(feature = "alter-impl")]
+pub(crate) mod alter;
+pub(crate) mod mine;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "alter-impl")] {
-        mod alter;
         pub use alter::*;
     } else {
-        mod mine;
         pub use self::mine::*;
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::paranoid_hash::tester::run_bit_tests;
+    use crate::paranoid_hash::tester::run_const_tests;
+    use crate::paranoid_hash::tester::BitTestData;
     use crate::paranoid_hash::tester::HasherTestWrapper;
     use crate::paranoid_hash::tester::TestData;
 
@@ -106,6 +144,56 @@ mod tests {
         0x40, 0xd2,
     ];
 
+    const TEST_447: &[u8] = &[
+        0x1c, 0x2e, 0x2b, 0xb8, 0x56, 0x9d, 0x80, 0x6c, 0x12, 0x51, 0xdc, 0xc9, 0xbe, 0xe3, 0x89,
+        0x12, 0x0e, 0xba, 0xee, 0xa3, 0xc2, 0xd8, 0x54, 0x5a, 0x78, 0x76, 0x0c, 0x5a, 0xa6, 0x58,
+        0x45, 0xb8, 0x5d, 0xe4, 0xd4, 0xba, 0xb5, 0xb9, 0xe4, 0x52, 0xcc, 0xec, 0x7f, 0xfa, 0x8e,
+        0xff, 0xb5, 0xe8, 0xec, 0xb3, 0xe9, 0xf9, 0x71, 0xa6, 0x55, 0x89,
+    ];
+    const TEST_511: &[u8] = &[
+        0xf5, 0x9e, 0x9b, 0xd0, 0x9f, 0x6a, 0xfa, 0xbb, 0x26, 0xae, 0x04, 0x61, 0x36, 0x1e, 0x19,
+        0x8b, 0x74, 0x36, 0x45, 0x88, 0x7d, 0x6b, 0x1e, 0xd8, 0x10, 0x1d, 0xb9, 0xb8, 0x58, 0x7f,
+        0x0c, 0x2a, 0x3a, 0x22, 0x0c, 0x14, 0x0a, 0xbf, 0x82, 0x41, 0x50, 0x5e, 0x00, 0xc5, 0x16,
+        0x7e, 0x4d, 0x12, 0x02, 0xb0, 0x39, 0x92, 0xac, 0xfa, 0x0f, 0x9d, 0xe5, 0x17, 0x87, 0xcd,
+        0x4e, 0xf2, 0x73, 0x2f,
+    ];
+
+    const SHA2_224_BIT_TESTS: &[BitTestData] = &[BitTestData {
+        data: &[0x68],
+        bit_len: 5,
+        result: "e3b048552c3c387bcab37f6eb06bb79b96a4aee5ff27f51531a9551c",
+    }];
+
+    const SHA2_256_BIT_TESTS: &[BitTestData] = &[
+        BitTestData {
+            data: &[0x68],
+            bit_len: 5,
+            result: "d6d3e02a31a84a8caa9718ed6c2057be09db45e7823eb5079ce7a573a3760f95",
+        },
+        BitTestData {
+            data: TEST_447,
+            bit_len: 447,
+            result: "fb7c9f8efa20be0ed797e8437f148a174b6d1863fd5525ea14f12e39248ab276",
+        },
+        BitTestData {
+            data: TEST_511,
+            bit_len: 511,
+            result: "29e582fa1282fa78104f5d8ce937203e44e0b4d8e826be7898ca618d65b7d3cf",
+        },
+    ];
+
+    const SHA2_384_BIT_TESTS: &[BitTestData] = &[BitTestData {
+        data: &[0x10],
+        bit_len: 5,
+        result: "8d17be79e32b6718e07d8a603eb84ba0478f7fcfd1bb93995f7d1149e09143ac1ffcfc56820e469f3878d957a15a3fe4",
+    }];
+
+    const SHA2_512_BIT_TESTS: &[BitTestData] = &[BitTestData {
+        data: &[0xb0],
+        bit_len: 5,
+        result: "d4ee29a9e90985446b913cf1d1376c836f4be2c1cf3cada0720a6bf4857d886a7ecb3c4e4c0fa8c7f95214e41dc1b0d21b22a84cc03bf8ce4845f34dd5bdbad4",
+    }];
+
     const SHA2_224_TESTS: &[TestData] = &[
         TestData {
             data: TEST1,
@@ -268,4 +356,67 @@ mod tests {
 
         HasherTestWrapper::new(SHA2_512::new()).run_tests(SHA2_512_TESTS);
     }
+
+    #[cfg(not(feature = "alter-impl"))]
+    #[test]
+    fn bit_tests() {
+        run_bit_tests(SHA2_224::new(), SHA2_224_BIT_TESTS);
+
+        run_bit_tests(SHA2_256::new(), SHA2_256_BIT_TESTS);
+
+        run_bit_tests(SHA2_384::new(), SHA2_384_BIT_TESTS);
+
+        run_bit_tests(SHA2_512::new(), SHA2_512_BIT_TESTS);
+    }
+
+    #[cfg(feature = "alter-impl")]
+    #[test]
+    fn bit_tests() {
+        use crate::paranoid_hash::BitHasher;
+
+        fn assert_unsupported<H: BitHasher>(mut hasher: H, tests: &[BitTestData]) {
+            for test in tests {
+                let full_blocks = (test.bit_len / 8) / hasher.block_size() * hasher.block_size();
+                hasher.update(&test.data[..full_blocks]).unwrap();
+                assert!(hasher
+                    .update_last_bits(&test.data[full_blocks..], test.bit_len - full_blocks * 8)
+                    .is_err());
+                hasher.reset();
+            }
+        }
+
+        assert_unsupported(SHA2_224::new(), SHA2_224_BIT_TESTS);
+
+        assert_unsupported(SHA2_256::new(), SHA2_256_BIT_TESTS);
+
+        assert_unsupported(SHA2_384::new(), SHA2_384_BIT_TESTS);
+
+        assert_unsupported(SHA2_512::new(), SHA2_512_BIT_TESTS);
+
+        run_bit_tests(
+            SHA2_256::new(),
+            &[BitTestData {
+                data: TEST1,
+                bit_len: 24,
+                result: "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            }],
+        );
+    }
+
+    #[test]
+    fn const_tests() {
+        const DIGEST: [u8; SHA2_256::DIGEST_SIZE] = SHA2_256::hash_const(b"abc");
+        assert_eq!(
+            hex::encode(DIGEST),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        run_const_tests(SHA2_224::new(), SHA2_224::hash_const);
+
+        run_const_tests(SHA2_256::new(), SHA2_256::hash_const);
+
+        run_const_tests(SHA2_384::new(), SHA2_384::hash_const);
+
+        run_const_tests(SHA2_512::new(), SHA2_512::hash_const);
+    }
 }
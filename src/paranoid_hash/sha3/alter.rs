@@ -0,0 +1,24 @@
+use ambassador::Delegate;
+
+use super::{
+    Permutation, SHA3Core, SHA3_224_CAPACITY, SHA3_256_CAPACITY, SHA3_384_CAPACITY,
+    SHA3_512_CAPACITY, SLICE_SIZE, STATE_SIZE,
+};
+use crate::{
+    paranoid_hash::{BitHasher, Hasher},
+    Result,
+};
+
+// Only the permutation of the keccak crate is used, the rest is shared with mine, so the state
+// has the same layout.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct KeccakF;
+
+impl Permutation for KeccakF {
+    #[inline(always)]
+    fn permute(state: &mut [u64; SLICE_SIZE]) {
+        keccak::f1600(state);
+    }
+}
+
+create_sha3!(KeccakF);
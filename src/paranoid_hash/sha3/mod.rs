@@ -0,0 +1,883 @@
+use alloc::vec::Vec;
+use core::{marker::PhantomData, mem::size_of};
+
+use crate::{
+    paranoid_hash::{
+        hash_helper::{read_u64_const, slice_as_chunks, write_u64_const},
+        state::{StateReader, StateWriter},
+        BitHasher, Hasher,
+    },
+    Error, Result,
+};
+
+macro_rules! impl_common {
+    ( $struct:ty, $base:ty ) => {
+        impl $struct {
+            pub const BLOCK_SIZE: usize = <$base>::BLOCK_SIZE;
+            pub const DIGEST_SIZE: usize = <$base>::DIGEST_SIZE;
+
+            pub const fn new() -> Self {
+                Self(<$base>::new())
+            }
+
+            /// Hashes `data` in one go, usable in const contexts.
+            pub const fn hash_const(data: &[u8]) -> [u8; Self::DIGEST_SIZE] {
+                <$base>::hash_const(data)
+            }
+        }
+    };
+}
+
+// Defines SHA3_224 to SHA3_512 on top of the given Keccak-f[1600] permutation.
+macro_rules! create_sha3 {
+    ( $permutation:ty ) => {
+        create_sha3!($permutation, SHA3_224, SHA3_224_CAPACITY);
+        create_sha3!($permutation, SHA3_256, SHA3_256_CAPACITY);
+        create_sha3!($permutation, SHA3_384, SHA3_384_CAPACITY);
+        create_sha3!($permutation, SHA3_512, SHA3_512_CAPACITY);
+    };
+    ( $permutation:ty, $struct:ident, $capacity:ident ) => {
+        #[derive(Delegate, Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        #[delegate(Hasher)]
+        #[delegate(BitHasher)]
+        pub struct $struct(
+            SHA3Core<$permutation, { $capacity / 2 / 8 }, { (STATE_SIZE - $capacity) / 8 }>,
+        );
+        impl_common!($struct,
+            SHA3Core<$permutation, { $capacity / 2 / 8 }, { (STATE_SIZE - $capacity) / 8 }>);
+    };
+}
+
+/// Keccak-f[1600], the only part of SHA-3 that differs between the backends.
+trait Permutation: Clone + 'static {
+    fn permute(state: &mut [u64; SLICE_SIZE]);
+}
+
+#[cfg(feature = "alter-impl")]
+pub(crate) mod alter;
+pub(crate) mod mine;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "alter-impl")] {
+        pub use alter::{SHA3_224, SHA3_256, SHA3_384, SHA3_512};
+    } else {
+        pub use self::mine::{SHA3_224, SHA3_256, SHA3_384, SHA3_512};
+    }
+}
+
+// DIGEST_SIZE = CAPACITY / 2 / 8
+// RATE_IN_U8 = (STATE_SIZE - CAPACITY) / 8
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct SHA3Core<P, const DIGEST_SIZE: usize, const RATE_IN_U8: usize> {
+    state: [u64; STATE_SIZE / 8 / size_of::<u64>()],
+    is_done: bool,
+    digest: [u8; DIGEST_SIZE],
+
+    temp: [u8; RATE_IN_U8],
+    pointer: usize,
+
+    permutation: PhantomData<P>,
+}
+
+impl<P: Permutation, const DIGEST_SIZE: usize, const RATE_IN_U8: usize>
+    SHA3Core<P, DIGEST_SIZE, RATE_IN_U8>
+{
+    const BLOCK_SIZE: usize = RATE_IN_U8;
+    const DIGEST_SIZE: usize = DIGEST_SIZE;
+    const NAME: &'static str = match DIGEST_SIZE {
+        28 => "SHA3_224",
+        32 => "SHA3_256",
+        48 => "SHA3_384",
+        64 => "SHA3_512",
+        _ => panic!("Invalid digest size"),
+    };
+
+    const fn new() -> Self {
+        Self {
+            state: [0; STATE_SIZE / 8 / size_of::<u64>()],
+            is_done: false,
+            digest: [0; DIGEST_SIZE],
+            temp: [0; RATE_IN_U8],
+            pointer: 0,
+            permutation: PhantomData,
+        }
+    }
+
+    // Always uses the native permutation, since the one of the backend may not be const.
+    const fn hash_const(data: &[u8]) -> [u8; DIGEST_SIZE] {
+        let mut state = [0; SLICE_SIZE];
+        let full_blocks = data.len() - data.len() % RATE_IN_U8;
+        let mut offset = 0;
+        while offset < full_blocks {
+            xor_block_const(&mut state, data, offset, RATE_IN_U8);
+            keccak_f(&mut state);
+            offset += RATE_IN_U8;
+        }
+
+        // the domain separation bits 01, then the padding
+        let mut last = [0; RATE_IN_U8];
+        let mut i = 0;
+        while offset + i < data.len() {
+            last[i] = data[offset + i];
+            i += 1;
+        }
+        last[i] = 0x06;
+        last[RATE_IN_U8 - 1] |= 0x80;
+
+        xor_block_const(&mut state, &last, 0, RATE_IN_U8);
+        keccak_f(&mut state);
+
+        write_u64_const(&state, false)
+    }
+
+    #[inline(always)]
+    fn update_rate_block(&mut self, rate_block: &[u8]) {
+        let mut block = [0u64; SLICE_SIZE];
+        for (c, b) in slice_as_chunks(rate_block).iter().zip(block.iter_mut()) {
+            *b = u64::from_le_bytes(*c);
+        }
+
+        for (s, b) in self.state.iter_mut().zip(block.iter()) {
+            *s ^= *b;
+        }
+
+        P::permute(&mut self.state);
+    }
+}
+
+impl<P: Permutation, const DIGEST_SIZE: usize, const RATE_IN_U8: usize> Hasher
+    for SHA3Core<P, DIGEST_SIZE, RATE_IN_U8>
+{
+    fn update(&mut self, data: &[u8]) -> Result<()> {
+        if self.is_done {
+            return Err(Error::UpdatingAfterFinished);
+        }
+
+        let mut chunks = if self.pointer == 0 {
+            data.chunks_exact(RATE_IN_U8)
+        } else {
+            let mut copied = 0;
+            for (t, d) in self.temp[self.pointer..].iter_mut().zip(data.iter()) {
+                *t = *d;
+                copied += 1;
+            }
+
+            self.pointer += copied;
+
+            if self.pointer == self.temp.len() {
+                self.pointer = 0;
+
+                let temp = self.temp;
+
+                self.update_rate_block(&temp);
+            }
+
+            data[copied..].chunks_exact(RATE_IN_U8)
+        };
+
+        for chunk in &mut chunks {
+            self.update_rate_block(chunk);
+        }
+
+        self.temp[self.pointer..self.pointer + chunks.remainder().len()]
+            .copy_from_slice(chunks.remainder());
+
+        self.pointer += chunks.remainder().len();
+
+        Ok(())
+    }
+
+    fn update_last(&mut self, data: &[u8]) -> Result<()> {
+        self.update_last_bits(data, data.len() * 8)
+    }
+
+    fn digest(&self) -> Result<&[u8]> {
+        if !self.is_done {
+            return Err(Error::NotFinished);
+        }
+
+        Ok(&self.digest)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    fn block_size(&self) -> usize {
+        Self::BLOCK_SIZE
+    }
+
+    fn digest_size(&self) -> usize {
+        Self::DIGEST_SIZE
+    }
+
+    fn save_state(&self) -> Result<Vec<u8>> {
+        let mut writer = StateWriter::new(Self::NAME);
+        writer.write(&self.state);
+        writer.write(&self.is_done);
+        writer.write(&self.digest);
+        writer.write(&self.temp);
+        writer.write(&self.pointer);
+        Ok(writer.finish())
+    }
+
+    fn restore_state(&mut self, state: &[u8]) -> Result<()> {
+        let mut reader = StateReader::new(state, Self::NAME)?;
+        let state = reader.read()?;
+        let is_done = reader.read()?;
+        let digest = reader.read()?;
+        let temp = reader.read()?;
+        let pointer = reader.read()?;
+        reader.finish()?;
+
+        if pointer >= RATE_IN_U8 {
+            return Err(Error::InvalidState("buffer position out of range"));
+        }
+
+        self.state = state;
+        self.is_done = is_done;
+        self.digest = digest;
+        self.temp = temp;
+        self.pointer = pointer;
+        Ok(())
+    }
+
+    impl_box_clone!();
+}
+
+impl<P: Permutation, const DIGEST_SIZE: usize, const RATE_IN_U8: usize> BitHasher
+    for SHA3Core<P, DIGEST_SIZE, RATE_IN_U8>
+{
+    fn update_last_bits(&mut self, data: &[u8], bit_len: usize) -> Result<()> {
+        if self.is_done {
+            return Err(Error::UpdatingAfterFinished);
+        }
+
+        if data.len() != bit_len.div_ceil(8) {
+            return Err(Error::BitLengthMismatched(bit_len, data.len()));
+        }
+
+        let byte_len = bit_len / 8;
+        let extra_bits = bit_len % 8;
+
+        self.update(&data[..byte_len])?;
+
+        // message bits, then the domain separation bits 01 and the first padding bit
+        let mut last_bits = (0b01100000u8.reverse_bits() as u16) << extra_bits;
+        if extra_bits != 0 {
+            last_bits |= (data[byte_len] & (0xff >> (8 - extra_bits))) as u16;
+        }
+        let [low, high] = last_bits.to_le_bytes();
+
+        self.temp[self.pointer..].fill(0);
+        self.temp[self.pointer] = low;
+
+        if high != 0 {
+            if self.pointer + 1 == self.temp.len() {
+                let temp = self.temp;
+                self.update_rate_block(&temp);
+
+                self.temp.fill(0);
+                self.temp[0] = high;
+            } else {
+                self.temp[self.pointer + 1] = high;
+            }
+        }
+
+        let last = self.temp.len() - 1;
+        self.temp[last] |= 0b00000001u8.reverse_bits();
+
+        let temp = self.temp;
+
+        self.update_rate_block(&temp);
+
+        for (d, s) in self.digest.chunks_mut(8).zip(self.state.iter()) {
+            let bytes = s.to_le_bytes();
+            d.copy_from_slice(&bytes[..d.len()]);
+        }
+
+        self.is_done = true;
+
+        Ok(())
+    }
+}
+
+impl<P: Permutation, const DIGEST_SIZE: usize, const RATE_IN_U8: usize> Default
+    for SHA3Core<P, DIGEST_SIZE, RATE_IN_U8>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A Keccak-f\[1600\] sponge with a configurable rate and domain separation suffix.
+///
+/// `rate` is in bytes and must be less than the 200 bytes state. `suffix` holds the domain
+/// separation bits followed by the first bit of the padding, e.g. 0x06 for SHA-3, 0x1f for SHAKE
+/// and 0x01 for the original Keccak.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct KeccakSponge {
+    state: [u64; SLICE_SIZE],
+    rate: usize,
+    suffix: u8,
+    pointer: usize,
+    is_squeezing: bool,
+}
+
+impl KeccakSponge {
+    pub const STATE_SIZE: usize = STATE_SIZE / 8;
+
+    pub const fn new(rate: usize, suffix: u8) -> Result<Self> {
+        if rate == 0 || rate >= Self::STATE_SIZE {
+            return Err(Error::IncorrectRate(rate, Self::STATE_SIZE));
+        }
+
+        Ok(Self {
+            state: [0; SLICE_SIZE],
+            rate,
+            suffix,
+            pointer: 0,
+            is_squeezing: false,
+        })
+    }
+
+    pub const fn rate(&self) -> usize {
+        self.rate
+    }
+
+    pub const fn suffix(&self) -> u8 {
+        self.suffix
+    }
+
+    pub const fn is_squeezing(&self) -> bool {
+        self.is_squeezing
+    }
+
+    /// Raw access to the state lanes, for constructions like duplex which work on the state directly.
+    pub fn state(&self) -> &[u64; SLICE_SIZE] {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut [u64; SLICE_SIZE] {
+        &mut self.state
+    }
+
+    /// Applies Keccak-f\[1600\] to the state.
+    pub fn permute(&mut self) {
+        keccak_f(&mut self.state);
+    }
+
+    pub fn absorb(&mut self, data: &[u8]) -> Result<()> {
+        if self.is_squeezing {
+            return Err(Error::UpdatingAfterFinished);
+        }
+
+        for byte in data {
+            self.xor_byte(self.pointer, *byte);
+            self.pointer += 1;
+
+            if self.pointer == self.rate {
+                self.permute();
+                self.pointer = 0;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Pads the absorbed data on the first call, then fills `output` with the squeezed bytes.
+    /// Can be called repeatedly to get an arbitrary long output.
+    pub fn squeeze(&mut self, output: &mut [u8]) {
+        if !self.is_squeezing {
+            self.xor_byte(self.pointer, self.suffix);
+            self.xor_byte(self.rate - 1, 0x80);
+            self.permute();
+            self.pointer = 0;
+            self.is_squeezing = true;
+        }
+
+        for byte in output {
+            if self.pointer == self.rate {
+                self.permute();
+                self.pointer = 0;
+            }
+
+            *byte = self.state[self.pointer / 8].to_le_bytes()[self.pointer % 8];
+            self.pointer += 1;
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.state = [0; SLICE_SIZE];
+        self.pointer = 0;
+        self.is_squeezing = false;
+    }
+
+    #[inline(always)]
+    fn xor_byte(&mut self, index: usize, byte: u8) {
+        self.state[index / 8] ^= (byte as u64) << (index % 8 * 8);
+    }
+}
+
+// XORs `rate` bytes of `data` from `offset` into the state.
+const fn xor_block_const(state: &mut [u64; SLICE_SIZE], data: &[u8], offset: usize, rate: usize) {
+    let mut i = 0;
+    while i < rate / 8 {
+        state[i] ^= read_u64_const::<1>(data, offset + i * 8, false)[0];
+        i += 1;
+    }
+}
+
+#[inline(always)]
+const fn theta(a: &mut [u64; SLICE_SIZE]) {
+    let mut c = [0u64; 5];
+    let mut x = 0;
+    while x < 5 {
+        c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+        x += 1;
+    }
+
+    let mut d = [0u64; 5];
+    let mut x = 0;
+    while x < 5 {
+        d[x] = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+        x += 1;
+    }
+
+    let mut i = 0;
+    while i < SLICE_SIZE {
+        a[i] ^= d[i % 5];
+        i += 1;
+    }
+}
+
+#[inline(always)]
+const fn rho(a: &mut [u64; SLICE_SIZE]) {
+    let mut t = 0;
+    while t < 24 {
+        let index = RHO_INDICES[t];
+        a[index] = a[index].rotate_left(RHO_OFFSETS[t]);
+        t += 1;
+    }
+}
+
+#[inline(always)]
+const fn pi(a: &mut [u64; SLICE_SIZE]) {
+    let mut a_prime = [0; SLICE_SIZE];
+
+    let mut y = 0;
+    while y < 5 {
+        let mut x = 0;
+        while x < 5 {
+            a_prime[x + y * 5] = a[(x + 3 * y) % 5 + x * 5];
+            x += 1;
+        }
+        y += 1;
+    }
+
+    *a = a_prime;
+}
+
+#[inline(always)]
+const fn chi(a: &mut [u64; SLICE_SIZE]) {
+    let mut a_prime = [0; SLICE_SIZE];
+
+    let mut y = 0;
+    while y < 5 {
+        let mut x = 0;
+        while x < 5 {
+            a_prime[x + y * 5] = a[x + y * 5] ^ !a[(x + 1) % 5 + y * 5] & a[(x + 2) % 5 + y * 5];
+            x += 1;
+        }
+        y += 1;
+    }
+
+    *a = a_prime;
+}
+
+#[inline(always)]
+const fn iota(a: &mut [u64; SLICE_SIZE], i: usize) {
+    a[0] ^= RC_TABLE[i];
+}
+
+#[inline(always)]
+const fn keccak_f(a: &mut [u64; SLICE_SIZE]) {
+    let mut i = 0;
+    while i < ROUND_COUNT {
+        theta(a);
+        rho(a);
+        pi(a);
+        chi(a);
+        iota(a, i);
+        i += 1;
+    }
+}
+
+const fn rho_indices() -> [usize; 24] {
+    let mut table = [0; 24];
+
+    let mut x = 1;
+    let mut y = 0;
+
+    let mut t = 0;
+    while t < 24 {
+        table[t] = x + y * 5;
+        let old_y = y;
+        y = (2 * x + 3 * y) % 5;
+        x = old_y;
+
+        t += 1;
+    }
+
+    table
+}
+
+const fn rho_right_shift_offsets() -> [u32; 24] {
+    let mut table = [0; 24];
+
+    let mut t = 0;
+    while t < 24 {
+        table[t as usize] = ((t + 1) * (t + 2)) / 2;
+
+        t += 1;
+    }
+
+    table
+}
+
+const fn rc_table() -> [u64; ROUND_COUNT] {
+    let mut switch_table = [0; 255];
+    switch_table[0] = 1;
+    let mut t = 1;
+    while t < 255 {
+        let mut i = 1;
+        let mut r = [0, 1, 0, 0, 0, 0, 0, 0, 0];
+        while i <= t {
+            r[0] = 0;
+
+            r[0] ^= r[8];
+            r[4] ^= r[8];
+            r[5] ^= r[8];
+            r[6] ^= r[8];
+            let mut ri = 8;
+            while ri > 0 {
+                r[ri] = r[ri - 1];
+
+                ri -= 1;
+            }
+
+            i += 1;
+        }
+
+        switch_table[t] = r[1];
+
+        t += 1;
+    }
+
+    let mut rc_table = [0u64; ROUND_COUNT];
+    let mut i = 0;
+    while i < ROUND_COUNT {
+        let mut rc = 0;
+        let mut j = 0;
+        while j <= E {
+            rc |= (1u64 << (2usize.pow(j as u32) - 1)) * switch_table[j + 7 * i];
+
+            j += 1;
+        }
+
+        rc_table[i] = rc;
+
+        i += 1;
+    }
+
+    rc_table
+}
+
+const STATE_SIZE: usize = 1600;
+const SLICE_SIZE: usize = 5 * 5;
+// log2(STATE_SIZE / SLICE_SIZE)
+const E: usize = 6;
+const ROUND_COUNT: usize = 24;
+
+const RHO_INDICES: [usize; 24] = rho_indices();
+const RHO_OFFSETS: [u32; 24] = rho_right_shift_offsets();
+const RC_TABLE: [u64; ROUND_COUNT] = rc_table();
+
+const SHA3_224_CAPACITY: usize = 448;
+const SHA3_256_CAPACITY: usize = 512;
+const SHA3_384_CAPACITY: usize = 768;
+const SHA3_512_CAPACITY: usize = 1024;
+
+#[cfg(test)]
+mod tests {
+    use crate::paranoid_hash::tester::run_bit_tests;
+    use crate::paranoid_hash::tester::run_const_tests;
+    use crate::paranoid_hash::tester::BitTestData;
+    use crate::paranoid_hash::tester::HasherTestWrapper;
+    use crate::paranoid_hash::tester::TestData;
+
+    use super::KeccakSponge;
+    use super::SHA3_224;
+    use super::SHA3_256;
+    use super::SHA3_384;
+    use super::SHA3_512;
+
+    const SHA3_224_TESTS: &[TestData] = &[
+        TestData {
+            data: &[],
+            repeat: 1,
+            result: "6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7",
+        },
+        TestData {
+            data: &[0xa3],
+            repeat: 200,
+            result: "9376816aba503f72f96ce7eb65ac095deee3be4bf9bbc2a1cb7e11e0",
+        },
+    ];
+
+    const SHA3_256_TESTS: &[TestData] = &[
+        TestData {
+            data: &[],
+            repeat: 1,
+            result: "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a",
+        },
+        TestData {
+            data: &[0xa3],
+            repeat: 200,
+            result: "79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787",
+        },
+    ];
+
+    const SHA3_384_TESTS: &[TestData] = &[
+        TestData {
+            data: &[],
+            repeat: 1,
+            result: "0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2ac3713831264adb47fb6bd1e058d5f004",
+        },
+        TestData {
+            data: &[0xa3],
+            repeat: 200,
+            result: "1881de2ca7e41ef95dc4732b8f5f002b189cc1e42b74168ed1732649ce1dbcdd76197a31fd55ee989f2d7050dd473e8f",
+        },
+    ];
+
+    const SHA3_512_TESTS: &[TestData] = &[
+        TestData {
+            data: &[],
+            repeat: 1,
+            result: "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26",
+        },
+        TestData {
+            data: &[0xa3],
+            repeat: 200,
+            result: "e76dfad22084a8b1467fcf2ffa58361bec7628edf5f3fdc0e4805dc48caeeca81b7c13c30adf52a3659584739a2df46be589c51ca1a4a8416df6545a1ce8ba00",
+        },
+    ];
+
+    #[test]
+    fn tests_from_nist() {
+        HasherTestWrapper::new(SHA3_224::new()).run_tests(SHA3_224_TESTS);
+
+        HasherTestWrapper::new(SHA3_256::new()).run_tests(SHA3_256_TESTS);
+
+        HasherTestWrapper::new(SHA3_384::new()).run_tests(SHA3_384_TESTS);
+
+        HasherTestWrapper::new(SHA3_512::new()).run_tests(SHA3_512_TESTS);
+    }
+
+    // bit strings 11001 and 110010100001101011011110100110, from the NIST examples
+    const MSG_5: &[u8] = &[0x13];
+    const MSG_30: &[u8] = &[0x53, 0x58, 0x7b, 0x19];
+    const MSG_1086: &[u8] = &[
+        0x44, 0x20, 0x82, 0x3c, 0xfd, 0xe6, 0xf1, 0xc2, 0x6b, 0x30, 0xf9, 0x0e, 0xc7, 0xdd, 0x01,
+        0xe4, 0x88, 0x75, 0x34, 0xa2, 0x0f, 0x0b, 0x0d, 0x04, 0xc3, 0x6e, 0xd8, 0x0e, 0x71, 0xe0,
+        0xfd, 0x77, 0xb0, 0x76, 0x70, 0xeb, 0x94, 0x0b, 0xd5, 0x33, 0x5f, 0x97, 0x3d, 0xaa, 0xd8,
+        0x61, 0x9b, 0x91, 0xff, 0xc9, 0x11, 0xf5, 0x7c, 0xce, 0xd4, 0x58, 0xbb, 0xbf, 0x2c, 0xe0,
+        0x37, 0x53, 0xc9, 0xbd, 0xfa, 0x0f, 0xf0, 0x16, 0x9d, 0xc9, 0x57, 0x56, 0x74, 0x06, 0x66,
+        0x76, 0xcf, 0xb0, 0xb4, 0xeb, 0x89, 0x02, 0xc4, 0x42, 0x69, 0xda, 0x1c, 0xf6, 0xba, 0x66,
+        0xd3, 0xf8, 0xb6, 0xd4, 0xb1, 0x00, 0xa9, 0xea, 0x0e, 0x75, 0x5a, 0x5c, 0x2e, 0x82, 0x10,
+        0x24, 0x2a, 0x08, 0xe7, 0x07, 0x8f, 0x7f, 0x89, 0x38, 0x5e, 0xb0, 0x94, 0x23, 0x55, 0x51,
+        0x82, 0x56, 0x8b, 0x96, 0xe8, 0xa4, 0xfe, 0xf2, 0x3a, 0x0c, 0x9f, 0xc5, 0xaf, 0xd7, 0x60,
+        0x84,
+    ];
+
+    const SHA3_224_BIT_TESTS: &[BitTestData] = &[
+        BitTestData {
+            data: MSG_5,
+            bit_len: 5,
+            result: "ffbad5da96bad71789330206dc6768ecaeb1b32dca6b3301489674ab",
+        },
+        BitTestData {
+            data: MSG_30,
+            bit_len: 30,
+            result: "d666a514cc9dba25ac1ba69ed3930460deaac9851b5f0baab007df3b",
+        },
+    ];
+
+    const SHA3_256_BIT_TESTS: &[BitTestData] = &[
+        BitTestData {
+            data: MSG_5,
+            bit_len: 5,
+            result: "7b0047cf5a456882363cbf0fb05322cf65f4b7059a46365e830132e3b5d957af",
+        },
+        BitTestData {
+            data: MSG_30,
+            bit_len: 30,
+            result: "c8242fef409e5ae9d1f1c857ae4dc624b92b19809f62aa8c07411c54a078b1d0",
+        },
+        BitTestData {
+            data: &[0x2b],
+            bit_len: 1,
+            result: "83f66216d2cc769e153bafce0181b61a471b4c6a213fc6f59a42985f976f33fe",
+        },
+        // the padding does not fit in the last rate block
+        BitTestData {
+            data: MSG_1086,
+            bit_len: 1086,
+            result: "51c7bfd563c86d4380e94f882dbe9bced475535b65f3cd76686c7e2a08b5aa1a",
+        },
+    ];
+
+    const SHA3_384_BIT_TESTS: &[BitTestData] = &[
+        BitTestData {
+            data: MSG_5,
+            bit_len: 5,
+            result: "737c9b491885e9bf7428e792741a7bf8dca9653471c3e148473f2c236b6a0a6455eb1dce9f779b4b6b237fef171b1c64",
+        },
+        BitTestData {
+            data: MSG_30,
+            bit_len: 30,
+            result: "955b4dd1be03261bd76f807a7efd432435c417362811b8a50c564e7ee9585e1ac7626dde2fdc030f876196ea267f08c3",
+        },
+    ];
+
+    const SHA3_512_BIT_TESTS: &[BitTestData] = &[
+        BitTestData {
+            data: MSG_5,
+            bit_len: 5,
+            result: "a13e01494114c09800622a70288c432121ce70039d753cadd2e006e4d961cb27544c1481e5814bdceb53be6733d5e099795e5e81918addb058e22a9f24883f37",
+        },
+        BitTestData {
+            data: MSG_30,
+            bit_len: 30,
+            result: "9834c05a11e1c5d3da9c740e1c106d9e590a0e530b6f6aaa7830525d075ca5db1bd8a6aa981a28613ac334934a01823cd45f45e49b6d7e6917f2f16778067bab",
+        },
+    ];
+
+    #[test]
+    fn bit_tests() {
+        run_bit_tests(SHA3_224::new(), SHA3_224_BIT_TESTS);
+
+        run_bit_tests(SHA3_256::new(), SHA3_256_BIT_TESTS);
+
+        run_bit_tests(SHA3_384::new(), SHA3_384_BIT_TESTS);
+
+        run_bit_tests(SHA3_512::new(), SHA3_512_BIT_TESTS);
+    }
+
+    // the default hashers are the alternative ones with alter-impl, so check mine as well
+    #[cfg(feature = "alter-impl")]
+    #[test]
+    fn backend_tests() {
+        use super::{alter, mine};
+
+        HasherTestWrapper::new(alter::SHA3_224::new()).run_tests(SHA3_224_TESTS);
+        HasherTestWrapper::new(alter::SHA3_256::new()).run_tests(SHA3_256_TESTS);
+        HasherTestWrapper::new(alter::SHA3_384::new()).run_tests(SHA3_384_TESTS);
+        HasherTestWrapper::new(alter::SHA3_512::new()).run_tests(SHA3_512_TESTS);
+
+        HasherTestWrapper::new(mine::SHA3_224::new()).run_tests(SHA3_224_TESTS);
+        HasherTestWrapper::new(mine::SHA3_256::new()).run_tests(SHA3_256_TESTS);
+        HasherTestWrapper::new(mine::SHA3_384::new()).run_tests(SHA3_384_TESTS);
+        HasherTestWrapper::new(mine::SHA3_512::new()).run_tests(SHA3_512_TESTS);
+
+        run_bit_tests(mine::SHA3_224::new(), SHA3_224_BIT_TESTS);
+        run_bit_tests(mine::SHA3_256::new(), SHA3_256_BIT_TESTS);
+        run_bit_tests(mine::SHA3_384::new(), SHA3_384_BIT_TESTS);
+        run_bit_tests(mine::SHA3_512::new(), SHA3_512_BIT_TESTS);
+    }
+
+    #[test]
+    fn const_tests() {
+        const DIGEST: [u8; SHA3_256::DIGEST_SIZE] = SHA3_256::hash_const(b"abc");
+        assert_eq!(
+            hex::encode(DIGEST),
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+
+        run_const_tests(SHA3_224::new(), SHA3_224::hash_const);
+
+        run_const_tests(SHA3_256::new(), SHA3_256::hash_const);
+
+        run_const_tests(SHA3_384::new(), SHA3_384::hash_const);
+
+        run_const_tests(SHA3_512::new(), SHA3_512::hash_const);
+    }
+
+    fn squeeze_hex(rate: usize, suffix: u8, data: &[u8], output_length: usize) -> String {
+        let mut sponge = KeccakSponge::new(rate, suffix).unwrap();
+        sponge.absorb(data).unwrap();
+        let mut output = vec![0; output_length];
+        sponge.squeeze(&mut output);
+        hex::encode(output)
+    }
+
+    #[test]
+    fn sponge_tests() {
+        // SHA3-256
+        assert_eq!(
+            squeeze_hex(136, 0x06, &[], 32),
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+        );
+        // original Keccak-256
+        assert_eq!(
+            squeeze_hex(136, 0x01, &[], 32),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        // SHAKE128
+        assert_eq!(
+            squeeze_hex(168, 0x1f, &[], 32),
+            "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26"
+        );
+        assert_eq!(
+            squeeze_hex(168, 0x1f, &[0xa3; 200], 32),
+            "131ab8d2b594946b9c81333f9bb6e0ce75c3b93104fa3469d3917457385da037"
+        );
+        // SHAKE256
+        assert_eq!(
+            squeeze_hex(136, 0x1f, &[], 64),
+            "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762fd75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+        );
+    }
+
+    #[test]
+    fn sponge_incremental_test() {
+        let data = [0xa3; 500];
+        let expected = squeeze_hex(168, 0x1f, &data, 1000);
+
+        let mut sponge = KeccakSponge::new(168, 0x1f).unwrap();
+        for chunk in data.chunks(7) {
+            sponge.absorb(chunk).unwrap();
+        }
+        let mut output = vec![0; 1000];
+        for chunk in output.chunks_mut(13) {
+            sponge.squeeze(chunk);
+        }
+        assert_eq!(hex::encode(output), expected);
+
+        assert!(sponge.absorb(&data).is_err());
+
+        sponge.reset();
+        sponge.absorb(&data).unwrap();
+        let mut output = vec![0; 1000];
+        sponge.squeeze(&mut output);
+        assert_eq!(hex::encode(output), expected);
+    }
+
+    #[test]
+    fn sponge_rate_test() {
+        assert!(KeccakSponge::new(0, 0x06).is_err());
+        assert!(KeccakSponge::new(KeccakSponge::STATE_SIZE, 0x06).is_err());
+        assert!(KeccakSponge::new(KeccakSponge::STATE_SIZE - 1, 0x06).is_ok());
+    }
+}
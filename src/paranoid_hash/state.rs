@@ -0,0 +1,392 @@
+use alloc::vec::Vec;
+
+use crate::{Error, Result};
+
+// Saved states start with the magic, the format version and the algorithm name, followed by the
+// fields of the hasher in little endian.
+const MAGIC: &[u8; 4] = b"MHST";
+const VERSION: u8 = 1;
+
+pub(crate) trait StateField: Sized {
+    fn write(&self, buffer: &mut Vec<u8>);
+    fn read(data: &mut &[u8]) -> Result<Self>;
+}
+
+fn take<'a>(data: &mut &'a [u8], length: usize) -> Result<&'a [u8]> {
+    if data.len() < length {
+        return Err(Error::InvalidState("state ended unexpectedly"));
+    }
+
+    let (head, tail) = data.split_at(length);
+    *data = tail;
+    Ok(head)
+}
+
+macro_rules! impl_state_field {
+    ( $( $type:ty ),* ) => {
+        $(
+            impl StateField for $type {
+                fn write(&self, buffer: &mut Vec<u8>) {
+                    buffer.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read(data: &mut &[u8]) -> Result<Self> {
+                    let bytes = take(data, core::mem::size_of::<Self>())?;
+                    Ok(Self::from_le_bytes(bytes.try_into().unwrap()))
+                }
+            }
+        )*
+    };
+}
+
+impl_state_field!(u8, u32, u64, u128);
+
+impl StateField for bool {
+    fn write(&self, buffer: &mut Vec<u8>) {
+        (*self as u8).write(buffer);
+    }
+
+    fn read(data: &mut &[u8]) -> Result<Self> {
+        match u8::read(data)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::InvalidState("invalid boolean value")),
+        }
+    }
+}
+
+impl StateField for usize {
+    fn write(&self, buffer: &mut Vec<u8>) {
+        (*self as u64).write(buffer);
+    }
+
+    fn read(data: &mut &[u8]) -> Result<Self> {
+        usize::try_from(u64::read(data)?).map_err(|_| Error::InvalidState("value out of range"))
+    }
+}
+
+impl<T: StateField + Copy + Default, const N: usize> StateField for [T; N] {
+    fn write(&self, buffer: &mut Vec<u8>) {
+        for value in self {
+            value.write(buffer);
+        }
+    }
+
+    fn read(data: &mut &[u8]) -> Result<Self> {
+        let mut values = [T::default(); N];
+        for value in values.iter_mut() {
+            *value = T::read(data)?;
+        }
+        Ok(values)
+    }
+}
+
+impl StateField for Vec<u8> {
+    fn write(&self, buffer: &mut Vec<u8>) {
+        self.len().write(buffer);
+        buffer.extend_from_slice(self);
+    }
+
+    fn read(data: &mut &[u8]) -> Result<Self> {
+        let length = usize::read(data)?;
+        Ok(take(data, length)?.to_vec())
+    }
+}
+
+pub(crate) struct StateWriter {
+    buffer: Vec<u8>,
+}
+
+impl StateWriter {
+    pub fn new(name: &str) -> Self {
+        let mut buffer = Vec::with_capacity(MAGIC.len() + 2 + name.len());
+        buffer.extend_from_slice(MAGIC);
+        buffer.push(VERSION);
+        buffer.push(name.len() as u8);
+        buffer.extend_from_slice(name.as_bytes());
+        Self { buffer }
+    }
+
+    pub fn write<T: StateField>(&mut self, value: &T) {
+        value.write(&mut self.buffer);
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.buffer
+    }
+}
+
+pub(crate) struct StateReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> StateReader<'a> {
+    pub fn new(mut data: &'a [u8], name: &str) -> Result<Self> {
+        if take(&mut data, MAGIC.len())? != MAGIC {
+            return Err(Error::InvalidState("not a saved hasher state"));
+        }
+
+        if u8::read(&mut data)? != VERSION {
+            return Err(Error::InvalidState("unsupported state version"));
+        }
+
+        let length = u8::read(&mut data)? as usize;
+        if take(&mut data, length)? != name.as_bytes() {
+            return Err(Error::InvalidState("state was saved by another algorithm"));
+        }
+
+        Ok(Self { data })
+    }
+
+    pub fn read<T: StateField>(&mut self) -> Result<T> {
+        T::read(&mut self.data)
+    }
+
+    pub fn finish(self) -> Result<()> {
+        if !self.data.is_empty() {
+            return Err(Error::InvalidState("unexpected data after state"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    #[cfg(feature = "has160")]
+    use crate::paranoid_hash::HAS160;
+    #[cfg(feature = "md2")]
+    use crate::paranoid_hash::MD2;
+    #[cfg(feature = "sha1")]
+    use crate::paranoid_hash::SHA1;
+    #[cfg(feature = "sha1dc")]
+    use crate::paranoid_hash::SHA1DC;
+    #[cfg(feature = "crc32")]
+    use crate::paranoid_hash::{CRC32, CRC32C};
+    #[cfg(feature = "edonr")]
+    use crate::paranoid_hash::{EDONR_256, EDONR_512};
+    #[cfg(feature = "fnv")]
+    use crate::paranoid_hash::{FNV1A_128, FNV1A_32, FNV1A_64, FNV1_128, FNV1_32, FNV1_64};
+    #[cfg(feature = "haval")]
+    use crate::paranoid_hash::{HAVAL_128_3, HAVAL_160_4, HAVAL_256_5};
+    #[cfg(feature = "murmur3")]
+    use crate::paranoid_hash::{MURMUR3_X64_128, MURMUR3_X86_32};
+    #[cfg(feature = "sha2")]
+    use crate::paranoid_hash::{SHA2_224, SHA2_256, SHA2_384, SHA2_512};
+    #[cfg(feature = "sha3")]
+    use crate::paranoid_hash::{SHA3_224, SHA3_256, SHA3_384, SHA3_512};
+    #[cfg(feature = "snefru")]
+    use crate::paranoid_hash::{SNEFRU_128, SNEFRU_256};
+    use crate::{
+        paranoid_hash::{native, BufferedHasher, Hasher},
+        Error,
+    };
+
+    const DATA: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+
+    fn resume_test(new: fn() -> Box<dyn Hasher>) {
+        let data = DATA.repeat(10);
+
+        let mut expected = BufferedHasher::new(new());
+        expected.update(&data).unwrap();
+        let expected = expected.finalize().unwrap().to_vec();
+
+        for split in [0, 1, 100, data.len()] {
+            let mut hasher = BufferedHasher::new(new());
+            hasher.update(&data[..split]).unwrap();
+            let state = hasher.save_state().unwrap();
+
+            // the original hasher can be dropped, only the saved state is needed
+            let mut hasher = BufferedHasher::new(new());
+            hasher.restore_state(&state).unwrap();
+            hasher.update(&data[split..]).unwrap();
+            assert_eq!(hasher.finalize().unwrap(), expected);
+        }
+
+        let mut hasher = new();
+        hasher.update_last(&[]).unwrap();
+        let mut restored = new();
+        restored
+            .restore_state(&hasher.save_state().unwrap())
+            .unwrap();
+        assert_eq!(restored.digest().unwrap(), hasher.digest().unwrap());
+        assert!(restored.update(&[]).is_err());
+    }
+
+    #[test]
+    fn resume_tests() {
+        #[cfg(feature = "crc32")]
+        resume_test(|| Box::new(CRC32::new()));
+        #[cfg(feature = "crc32")]
+        resume_test(|| Box::new(CRC32C::new()));
+        #[cfg(feature = "md2")]
+        resume_test(|| Box::new(MD2::new()));
+        #[cfg(feature = "md4")]
+        resume_test(|| Box::new(native::MD4::new()));
+        #[cfg(feature = "md5")]
+        resume_test(|| Box::new(native::MD5::new()));
+        #[cfg(feature = "sha1")]
+        resume_test(|| Box::new(SHA1::new()));
+        #[cfg(feature = "sha1dc")]
+        resume_test(|| Box::new(SHA1DC::new()));
+        #[cfg(feature = "sha2")]
+        resume_test(|| Box::new(SHA2_224::new()));
+        #[cfg(feature = "sha2")]
+        resume_test(|| Box::new(SHA2_256::new()));
+        #[cfg(feature = "sha2")]
+        resume_test(|| Box::new(SHA2_384::new()));
+        #[cfg(feature = "sha2")]
+        resume_test(|| Box::new(SHA2_512::new()));
+        #[cfg(feature = "sha3")]
+        resume_test(|| Box::new(SHA3_224::new()));
+        #[cfg(feature = "sha3")]
+        resume_test(|| Box::new(SHA3_256::new()));
+        #[cfg(feature = "sha3")]
+        resume_test(|| Box::new(SHA3_384::new()));
+        #[cfg(feature = "sha3")]
+        resume_test(|| Box::new(SHA3_512::new()));
+        #[cfg(feature = "murmur3")]
+        resume_test(|| Box::new(MURMUR3_X86_32::new()));
+        #[cfg(feature = "murmur3")]
+        resume_test(|| Box::new(MURMUR3_X64_128::new()));
+        #[cfg(feature = "fnv")]
+        resume_test(|| Box::new(FNV1_32::new()));
+        #[cfg(feature = "fnv")]
+        resume_test(|| Box::new(FNV1A_32::new()));
+        #[cfg(feature = "fnv")]
+        resume_test(|| Box::new(FNV1_64::new()));
+        #[cfg(feature = "fnv")]
+        resume_test(|| Box::new(FNV1A_64::new()));
+        #[cfg(feature = "fnv")]
+        resume_test(|| Box::new(FNV1_128::new()));
+        #[cfg(feature = "fnv")]
+        resume_test(|| Box::new(FNV1A_128::new()));
+        #[cfg(feature = "haval")]
+        resume_test(|| Box::new(HAVAL_128_3::new()));
+        #[cfg(feature = "haval")]
+        resume_test(|| Box::new(HAVAL_160_4::new()));
+        #[cfg(feature = "haval")]
+        resume_test(|| Box::new(HAVAL_256_5::new()));
+        #[cfg(feature = "snefru")]
+        resume_test(|| Box::new(SNEFRU_128::new()));
+        #[cfg(feature = "snefru")]
+        resume_test(|| Box::new(SNEFRU_256::new()));
+        #[cfg(feature = "has160")]
+        resume_test(|| Box::new(HAS160::new()));
+        #[cfg(feature = "edonr")]
+        resume_test(|| Box::new(EDONR_256::new()));
+        #[cfg(feature = "edonr")]
+        resume_test(|| Box::new(EDONR_512::new()));
+    }
+
+    // the md4 and md-5 crates keep their states private
+    #[cfg(all(feature = "alter-impl", feature = "md4", feature = "md5"))]
+    #[test]
+    fn unsupported_tests() {
+        use crate::paranoid_hash::rust_crypto;
+
+        let hasher = rust_crypto::MD5::new();
+        assert!(matches!(hasher.save_state(), Err(Error::StateUnsupported)));
+        let mut hasher = rust_crypto::MD4::new();
+        assert!(matches!(
+            hasher.restore_state(&[]),
+            Err(Error::StateUnsupported)
+        ));
+        assert!(hasher.box_clone().is_ok());
+    }
+
+    #[cfg(feature = "sha1")]
+    const SHA1_STATE: &str = "4d485354010453484131eb6849da1f7c372e528f4e88eb4b5283bdbd4ee70002000000000000000000000000000000000000000000000000000000";
+
+    // saved after hashing a part of the data, both implementations must be able to restore it
+    #[cfg(all(feature = "crc32", feature = "sha1"))]
+    #[test]
+    fn compatibility_tests() {
+        let mut hasher = CRC32::new();
+        hasher
+            .restore_state(&hex::decode("4d48535401054352433332a49df4ff0000000000").unwrap())
+            .unwrap();
+        hasher.update_last(&DATA[4..]).unwrap();
+        assert_eq!(hex::encode(hasher.digest().unwrap()), "414fa339");
+
+        let mut hasher = SHA1::new();
+        hasher
+            .restore_state(&hex::decode(SHA1_STATE).unwrap())
+            .unwrap();
+        hasher.update_last(DATA).unwrap();
+        assert_eq!(
+            hex::encode(hasher.digest().unwrap()),
+            "8c0a3eb55c328d17bbf6d7d75a86276a28681286"
+        );
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn invalid_state_tests() {
+        let mut hasher = SHA2_256::new();
+        hasher.update(&[0; SHA2_256::BLOCK_SIZE]).unwrap();
+        let state = hasher.save_state().unwrap();
+
+        let mut restored = SHA2_256::new();
+        assert!(matches!(
+            restored.restore_state(&state[..state.len() - 1]),
+            Err(Error::InvalidState(_))
+        ));
+        assert!(matches!(
+            restored.restore_state(&[state.as_slice(), &[0]].concat()),
+            Err(Error::InvalidState(_))
+        ));
+        assert!(matches!(
+            restored.restore_state(&state[1..]),
+            Err(Error::InvalidState(_))
+        ));
+        assert!(matches!(
+            SHA2_224::new().restore_state(&state),
+            Err(Error::InvalidState(_))
+        ));
+
+        let mut version = state.clone();
+        version[4] = 0;
+        assert!(matches!(
+            restored.restore_state(&version),
+            Err(Error::InvalidState(_))
+        ));
+
+        // failed restores leave the hasher untouched
+        assert_eq!(
+            restored.save_state().unwrap(),
+            SHA2_256::new().save_state().unwrap()
+        );
+        restored.restore_state(&state).unwrap();
+        assert_eq!(restored.save_state().unwrap(), state);
+    }
+
+    // the RustCrypto ones buffer updates which aren't whole blocks, that can't be saved
+    #[cfg(all(feature = "alter-impl", feature = "sha1"))]
+    #[test]
+    fn partial_block_tests() {
+        use crate::paranoid_hash::rust_crypto;
+
+        let data = DATA.repeat(2);
+        let mut hasher = rust_crypto::SHA1::new();
+        hasher.update(&data[..7]).unwrap();
+        assert!(matches!(
+            hasher.save_state(),
+            Err(Error::DataLengthMismatched(7, 64))
+        ));
+        hasher.update(&data[7..64]).unwrap();
+        let mut expected = native::SHA1::new();
+        expected.update(&data[..64]).unwrap();
+        let mut state = hasher.save_state().unwrap();
+        assert_eq!(state, expected.save_state().unwrap());
+
+        // a count of 513 bits, after the header and the chaining value
+        state[4 + 1 + 1 + 4 + 20] += 1;
+        assert!(matches!(
+            rust_crypto::SHA1::new().restore_state(&state),
+            Err(Error::InvalidState(_))
+        ));
+    }
+}
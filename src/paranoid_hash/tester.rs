@@ -1,7 +1,7 @@
 #![allow(dead_code)]
 #![cfg(test)]
 
-use super::Hasher;
+use super::{BitHasher, Hasher};
 
 pub(super) struct HasherTestWrapper<Base: Hasher> {
     base: Base,
@@ -58,3 +58,58 @@ pub(super) struct TestData<'a> {
     pub repeat: usize,
     pub result: &'a str,
 }
+
+pub(super) struct BitTestData<'a> {
+    pub data: &'a [u8],
+    pub bit_len: usize,
+    pub result: &'a str,
+}
+
+pub(super) fn run_bit_tests<Base: BitHasher>(mut base: Base, tests: &[BitTestData]) {
+    for test in tests {
+        let full_blocks = (test.bit_len / 8) / base.block_size() * base.block_size();
+        base.update(&test.data[..full_blocks]).unwrap();
+        base.update_last_bits(&test.data[full_blocks..], test.bit_len - full_blocks * 8)
+            .unwrap();
+        assert_eq!(hex::encode(base.digest().unwrap()), test.result);
+        base.reset();
+    }
+}
+
+/// Compares the const one-shot hashing with the hasher, on every length up to a few blocks.
+pub(super) fn run_const_tests<Base: Hasher, const N: usize>(
+    base: Base,
+    hash_const: fn(&[u8]) -> [u8; N],
+) {
+    let data: Vec<u8> = (0..300u32).map(|i| (i * 7 + 3) as u8).collect();
+    let mut wrapper = HasherTestWrapper::new(base);
+    for length in 0..data.len() {
+        wrapper.update(&data[..length]);
+        assert_eq!(
+            hash_const(&data[..length]),
+            *wrapper.finalize_reset(),
+            "{length}"
+        );
+    }
+}
+
+// The first 320 bytes of shattered-1.pdf and shattered-2.pdf from https://shattered.io,
+// which already contain the colliding blocks.
+pub(super) const SHATTERED_1: &str = concat!(
+    "255044462d312e330a25e2e3cfd30a0a0a312030206f626a0a3c3c2f57696474682032203020522f4865696768742033",
+    "203020522f547970652034203020522f537562747970652035203020522f46696c7465722036203020522f436f6c6f72",
+    "53706163652037203020522f4c656e6774682038203020522f42697473506572436f6d706f6e656e7420383e3e0a7374",
+    "7265616d0affd8fffe00245348412d3120697320646561642121212121852fec092339759c39b1a1c63c4c97e1fffe01",
+    "7346dc9166b67e118f029ab621b2560ff9ca67cca8c7f85ba84c79030c2b3de218f86db3a90901d5df45c14f26fedfb3",
+    "dc38e96ac22fe7bd728f0e45bce046d23c570feb141398bb552ef5a0a82be331fea48037b8b5d71f0e332edf93ac3500",
+    "eb4ddc0decc1a864790c782c76215660dd309791d06bd0af3f98cda4bc4629b1",
+);
+pub(super) const SHATTERED_2: &str = concat!(
+    "255044462d312e330a25e2e3cfd30a0a0a312030206f626a0a3c3c2f57696474682032203020522f4865696768742033",
+    "203020522f547970652034203020522f537562747970652035203020522f46696c7465722036203020522f436f6c6f72",
+    "53706163652037203020522f4c656e6774682038203020522f42697473506572436f6d706f6e656e7420383e3e0a7374",
+    "7265616d0affd8fffe00245348412d3120697320646561642121212121852fec092339759c39b1a1c63c4c97e1fffe01",
+    "7f46dc93a6b67e013b029aaa1db2560b45ca67d688c7f84b8c4c791fe02b3df614f86db1690901c56b45c1530afedfb7",
+    "6038e972722fe7ad728f0e4904e046c230570fe9d41398abe12ef5bc942be33542a4802d98b5d70f2a332ec37fac3514",
+    "e74ddc0f2cc1a874cd0c78305a21566461309789606bd0bf3f98cda8044629a1",
+);
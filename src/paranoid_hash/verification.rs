@@ -0,0 +1,111 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::paranoid_hash::{digest_value::constant_time_eq, HasherTag, SecurityStrength};
+
+/// Outcome of checking the digests of one piece of data against the expected ones.
+///
+/// A match is only as trustworthy as the strongest algorithm that matched, `is_weak_match` tells
+/// when every matched digest is from a weak algorithm, e.g. only MD5 was checked.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Verification {
+    matched: Vec<HasherTag>,
+    mismatched: Vec<HasherTag>,
+}
+
+impl Verification {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compares the digests in constant time and records the outcome.
+    pub fn check(&mut self, tag: HasherTag, expected: &[u8], actual: &[u8]) -> bool {
+        let is_match = constant_time_eq(expected, actual);
+        if is_match {
+            self.matched.push(tag);
+        } else {
+            self.mismatched.push(tag);
+        }
+
+        is_match
+    }
+
+    pub fn matched(&self) -> &[HasherTag] {
+        &self.matched
+    }
+
+    pub fn mismatched(&self) -> &[HasherTag] {
+        &self.mismatched
+    }
+
+    /// At least one digest was checked and all of them matched.
+    pub fn is_match(&self) -> bool {
+        !self.matched.is_empty() && self.mismatched.is_empty()
+    }
+
+    /// A match which only relies on weak algorithms.
+    pub fn is_weak_match(&self) -> bool {
+        self.is_match() && self.matched.iter().all(|tag| tag.is_weak())
+    }
+
+    /// Strength of the strongest matched algorithm.
+    pub fn strength(&self) -> Option<SecurityStrength> {
+        self.matched.iter().map(|tag| tag.security_strength()).max()
+    }
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (outcome, tags) = if !self.mismatched.is_empty() {
+            ("mismatch", &self.mismatched)
+        } else if self.is_weak_match() {
+            ("weak match", &self.matched)
+        } else if self.is_match() {
+            ("match", &self.matched)
+        } else {
+            return f.write_str("nothing verified");
+        };
+
+        write!(f, "{outcome} (")?;
+        for (i, tag) in tags.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{tag}")?;
+        }
+        f.write_str(")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![cfg_attr(not(feature = "all-algorithms"), allow(unused))]
+
+    use crate::paranoid_hash::{HasherTag, SecurityStrength};
+
+    use super::Verification;
+
+    #[cfg(all(feature = "crc32", feature = "md5", feature = "sha2"))]
+    #[test]
+    fn verification_tests() {
+        let mut verification = Verification::new();
+        assert!(!verification.is_match());
+        assert_eq!(verification.to_string(), "nothing verified");
+
+        assert!(verification.check(HasherTag::MD5, &[1, 2], &[1, 2]));
+        assert!(verification.is_match());
+        assert!(verification.is_weak_match());
+        assert_eq!(verification.strength(), Some(SecurityStrength::Broken));
+        assert_eq!(verification.to_string(), "weak match (md5)");
+
+        assert!(verification.check(HasherTag::SHA2_256, &[3], &[3]));
+        assert!(!verification.is_weak_match());
+        assert_eq!(verification.strength(), Some(SecurityStrength::Bits(128)));
+        assert_eq!(verification.to_string(), "match (md5, sha2-256)");
+
+        assert!(!verification.check(HasherTag::CRC32, &[4], &[5]));
+        assert!(!verification.is_match());
+        assert_eq!(verification.mismatched(), [HasherTag::CRC32]);
+        assert_eq!(verification.to_string(), "mismatch (crc32)");
+    }
+}